@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT_LANGUAGE};
+use url::Url;
+
+use crate::errors::TaigaClientError;
+use crate::retry::RetryPolicy;
+use crate::TaigaClient;
+
+/// Time allowed to establish a connection before giving up
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed between two reads of a response, so a hung server can't block forever
+/// Applies per read, so large downloads are not cut off.
+pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+const DEFAULT_USER_AGENT: &str = concat!("taiga-client/", env!("CARGO_PKG_VERSION"));
+
+/// Configures the HTTP client behind a `TaigaClient`
+/// Create one with `TaigaClient::builder`.
+#[derive(Debug, Clone)]
+pub struct TaigaClientBuilder {
+    api_base_url: Url,
+    connect_timeout: Duration,
+    read_timeout: Duration,
+    proxy_url: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    accept_invalid_certs: bool,
+    user_agent: String,
+    accept_language: Option<String>,
+    retry_policy: RetryPolicy,
+}
+
+impl TaigaClientBuilder {
+    pub(crate) fn new(api_base_url: Url) -> Self {
+        Self {
+            api_base_url,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+            proxy_url: None,
+            root_certificates: Vec::new(),
+            accept_invalid_certs: false,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            accept_language: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Send all requests through this HTTP(S) proxy, e.g. `http://proxy.corp:3128`
+    pub fn proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy_url = Some(proxy_url.into());
+        self
+    }
+
+    /// Trust the certificates in a PEM bundle in addition to the system roots
+    pub fn add_root_certificates_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Skip TLS certificate validation; only for local development instances
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Language Taiga uses for translated names and error messages, e.g. `de`
+    pub fn accept_language(mut self, language: impl Into<String>) -> Self {
+        self.accept_language = Some(language.into());
+        self
+    }
+
+    /// Retry policy used for idempotent requests
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> Result<TaigaClient, TaigaClientError> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .read_timeout(self.read_timeout)
+            .user_agent(self.user_agent);
+
+        if let Some(language) = &self.accept_language {
+            let value = HeaderValue::from_str(language).map_err(|_| {
+                TaigaClientError::InvalidConfig(format!("invalid Accept-Language '{}'", language))
+            })?;
+            let mut headers = HeaderMap::new();
+            headers.insert(ACCEPT_LANGUAGE, value);
+            builder = builder.default_headers(headers);
+        }
+
+        if let Some(proxy_url) = &self.proxy_url {
+            let proxy = reqwest::Proxy::all(proxy_url).map_err(|e| {
+                TaigaClientError::InvalidConfig(format!("invalid proxy '{}': {}", proxy_url, e))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+
+        for pem in &self.root_certificates {
+            let certificates = reqwest::Certificate::from_pem_bundle(pem).map_err(|e| {
+                TaigaClientError::InvalidConfig(format!("invalid PEM certificate: {}", e))
+            })?;
+            if certificates.is_empty() {
+                return Err(TaigaClientError::InvalidConfig(
+                    "PEM bundle contains no certificates".to_string(),
+                ));
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        if self.accept_invalid_certs {
+            log::warn!("TLS certificate validation is disabled");
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        Ok(TaigaClient {
+            client: builder.build()?,
+            api_base_url: self.api_base_url,
+            retry_policy: self.retry_policy,
+        })
+    }
+}
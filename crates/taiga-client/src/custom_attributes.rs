@@ -0,0 +1,94 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{
+    CustomAttributeDto, IssueCustomAttributesValuesDto, PatchIssueCustomAttributesValuesRequest,
+};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List the issue custom attribute definitions of a project
+    /// GET /api/v1/issue-custom-attributes?project={project_id}
+    pub async fn get_issue_custom_attributes(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<CustomAttributeDto>, TaigaClientError> {
+        let url = self.build_url("issue-custom-attributes")?;
+        log::info!(
+            "Fetching issue custom attributes for project {}",
+            project_id
+        );
+
+        let req = self
+            .client
+            .get(url)
+            .query(&[("project", project_id)])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        if response.status().is_success() {
+            Self::parse_json(response, "issue custom attributes").await
+        } else {
+            Err(Self::error_from_response(response, "Get issue custom attributes").await)
+        }
+    }
+
+    /// Fetch the custom attribute values of an issue
+    /// GET /api/v1/issues/custom-attributes-values/{issue_id}
+    pub async fn get_issue_custom_attributes_values(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<IssueCustomAttributesValuesDto, TaigaClientError> {
+        let url = self.build_url(&format!("issues/custom-attributes-values/{}", issue_id))?;
+        log::info!("Fetching custom attribute values for issue {}", issue_id);
+
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        if response.status().is_success() {
+            Self::parse_json(response, "issue custom attribute values").await
+        } else {
+            Err(Self::error_from_response(response, "Get issue custom attribute values").await)
+        }
+    }
+
+    /// Replace the custom attribute values of an issue
+    /// PATCH /api/v1/issues/custom-attributes-values/{issue_id}
+    /// Uses version field for optimistic locking - returns VersionConflict on mismatch
+    pub async fn patch_issue_custom_attributes_values(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+        request: PatchIssueCustomAttributesValuesRequest,
+    ) -> Result<IssueCustomAttributesValuesDto, TaigaClientError> {
+        let url = self.build_url(&format!("issues/custom-attributes-values/{}", issue_id))?;
+        log::info!(
+            "Patching custom attribute values for issue {} with version {}",
+            issue_id,
+            request.version
+        );
+
+        let req = self
+            .client
+            .patch(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!(
+            "Patch issue custom attribute values response status: {}",
+            response.status()
+        );
+
+        if response.status().is_success() {
+            Self::parse_json(response, "patched issue custom attribute values").await
+        } else {
+            Err(
+                Self::patch_error_from_response(response, "Patch issue custom attribute values")
+                    .await,
+            )
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{EpicDto, EpicRelatedUserStoryDto, LinkEpicUserStoryRequest, UserStoryDto};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List every epic of a project, following every page
+    /// GET /api/v1/epics?project={project_id}
+    pub async fn list_epics(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<EpicDto>, TaigaClientError> {
+        log::info!("Fetching epics for project {}", project_id);
+
+        let query = [("project".to_string(), project_id.to_string())];
+        let epics = self
+            .fetch_all_pages::<EpicDto>(token, "epics", &query)
+            .await?;
+        log::info!("Found {} epics in project {}", epics.len(), project_id);
+        Ok(epics)
+    }
+
+    /// List the user stories related to an epic, from any project
+    /// GET /api/v1/userstories?epic={epic_id}
+    pub async fn list_epic_user_stories(
+        &self,
+        token: &Secret<String>,
+        epic_id: i64,
+    ) -> Result<Vec<UserStoryDto>, TaigaClientError> {
+        log::info!("Fetching user stories of epic {}", epic_id);
+
+        let query = [("epic".to_string(), epic_id.to_string())];
+        self.fetch_all_pages::<UserStoryDto>(token, "userstories", &query)
+            .await
+    }
+
+    /// Link a user story to an epic
+    /// POST /api/v1/epics/{epic_id}/related_userstories
+    pub async fn link_epic_user_story(
+        &self,
+        token: &Secret<String>,
+        epic_id: i64,
+        user_story_id: i64,
+    ) -> Result<EpicRelatedUserStoryDto, TaigaClientError> {
+        let url = self.build_url(&format!("epics/{}/related_userstories", epic_id))?;
+        log::info!(
+            "Linking user story {} to epic {} at {}",
+            user_story_id,
+            epic_id,
+            url
+        );
+
+        let body = LinkEpicUserStoryRequest {
+            epic: epic_id,
+            user_story: user_story_id,
+        };
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&body);
+        let response = self.send(req).await?;
+
+        log::info!(
+            "Link epic user story response status: {}",
+            response.status()
+        );
+
+        if response.status().is_success() {
+            Self::parse_json(response, "epic related user story").await
+        } else {
+            Err(Self::error_from_response(response, "Link epic user story").await)
+        }
+    }
+
+    /// Remove the link between a user story and an epic
+    /// DELETE /api/v1/epics/{epic_id}/related_userstories/{user_story_id}
+    pub async fn unlink_epic_user_story(
+        &self,
+        token: &Secret<String>,
+        epic_id: i64,
+        user_story_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        let url = self.build_url(&format!(
+            "epics/{}/related_userstories/{}",
+            epic_id, user_story_id
+        ))?;
+        log::info!(
+            "Unlinking user story {} from epic {} at {}",
+            user_story_id,
+            epic_id,
+            url
+        );
+
+        let req = self.client.delete(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!(
+            "Unlink epic user story response status: {}",
+            response.status()
+        );
+
+        if response.status().is_success() || response.status() == StatusCode::NO_CONTENT {
+            Ok(())
+        } else {
+            Err(Self::error_from_response(response, "Unlink epic user story").await)
+        }
+    }
+}
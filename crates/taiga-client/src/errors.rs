@@ -1,6 +1,75 @@
 use reqwest::StatusCode;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
+/// Error details parsed from a failed Taiga response body
+/// Taiga sends `{"_error_message": "...", "_error_type": "..."}` for general errors
+/// and `{"field": ["reason", ...]}` for validation errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaigaApiError {
+    pub status: StatusCode,
+    pub message: Option<String>,
+    pub field_errors: BTreeMap<String, Vec<String>>,
+}
+
+impl TaigaApiError {
+    pub fn from_body(status: StatusCode, body: &str) -> Self {
+        let mut message = None;
+        let mut field_errors = BTreeMap::new();
+
+        match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(serde_json::Value::Object(map)) => {
+                for (key, value) in map {
+                    match key.as_str() {
+                        "_error_message" | "detail" => {
+                            message = message.or_else(|| value.as_str().map(str::to_string))
+                        }
+                        "_error_type" => {}
+                        _ => {
+                            let reasons = error_strings(&value);
+                            if !reasons.is_empty() {
+                                field_errors.insert(key, reasons);
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(serde_json::Value::String(text)) => message = Some(text),
+            _ => {}
+        }
+
+        Self {
+            status,
+            message: message.filter(|m| !m.trim().is_empty()),
+            field_errors,
+        }
+    }
+}
+
+impl fmt::Display for TaigaApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Taiga rejected the request ({})", self.status)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        for (field, reasons) in &self.field_errors {
+            write!(f, "; {}: {}", field, reasons.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+fn error_strings(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::String(s) => vec![s.clone()],
+        serde_json::Value::Array(items) => items.iter().flat_map(error_strings).collect(),
+        serde_json::Value::Null => vec![],
+        other => vec![other.to_string()],
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum TaigaClientError {
     #[error("HTTP Request failed: {0}")]
@@ -9,10 +78,7 @@ pub enum TaigaClientError {
     #[error("Invalid URL: {0}")]
     UrlParse(#[from] url::ParseError),
 
-    #[error("The requested endpoint was not found (404).")]
-    EndpointNotFound(StatusCode),
-
-    #[error("Invalid credentials or insufficient permissions (401/403).")]
+    #[error("Invalid or expired credentials (401).")]
     Unauthorized(StatusCode),
 
     #[error("Authentication failed with status: {0}")]
@@ -21,12 +87,52 @@ pub enum TaigaClientError {
     #[error("Version conflict: the resource was modified by another user (412)")]
     VersionConflict(StatusCode),
 
+    #[error("Rate limited by the Taiga server (429).")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("Taiga server error: {0}")]
+    ServerError(StatusCode),
+
+    #[error("{0}")]
+    Api(TaigaApiError),
+
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    #[error("Invalid client configuration: {0}")]
+    InvalidConfig(String),
+
     #[error("Invalid MIME type: {0}")]
     InvalidMimeType(String),
 
+    #[error("File is {size} bytes, larger than the {limit} byte upload limit")]
+    FileTooLarge { size: u64, limit: u64 },
+
+    #[error("File error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("The transfer was cancelled")]
+    Cancelled,
+
     #[error("Unknown error")]
     Unknown,
 }
+
+impl TaigaClientError {
+    /// Map a failed response to the matching error variant
+    /// Only 401 means the token is bad; other client errors except 429, including
+    /// permission errors (403) and missing resources (404), keep the parsed body as `Api`.
+    pub(crate) fn from_response(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => TaigaClientError::Unauthorized(status),
+            StatusCode::TOO_MANY_REQUESTS => TaigaClientError::RateLimited { retry_after },
+            s if s.is_server_error() => TaigaClientError::ServerError(s),
+            s if s.is_client_error() => TaigaClientError::Api(TaigaApiError::from_body(s, body)),
+            _ => TaigaClientError::AuthFailed(status),
+        }
+    }
+}
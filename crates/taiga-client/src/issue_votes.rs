@@ -0,0 +1,88 @@
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::VoterDto;
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// Subscribe the current user to an issue's notifications
+    /// POST /api/v1/issues/{issue_id}/watch
+    pub async fn watch_issue(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        self.post_issue_action(token, issue_id, "watch", "Watch Issue")
+            .await
+    }
+
+    /// Unsubscribe the current user from an issue's notifications
+    /// POST /api/v1/issues/{issue_id}/unwatch
+    pub async fn unwatch_issue(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        self.post_issue_action(token, issue_id, "unwatch", "Unwatch Issue")
+            .await
+    }
+
+    /// Add the current user's vote to an issue
+    /// POST /api/v1/issues/{issue_id}/upvote
+    pub async fn upvote_issue(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        self.post_issue_action(token, issue_id, "upvote", "Upvote Issue")
+            .await
+    }
+
+    /// Remove the current user's vote from an issue
+    /// POST /api/v1/issues/{issue_id}/downvote
+    pub async fn downvote_issue(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        self.post_issue_action(token, issue_id, "downvote", "Downvote Issue")
+            .await
+    }
+
+    /// List the users who voted for an issue, following every page
+    /// GET /api/v1/issues/{issue_id}/voters
+    pub async fn list_issue_voters(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<Vec<VoterDto>, TaigaClientError> {
+        log::info!("Fetching voters for issue {}", issue_id);
+
+        self.fetch_all_pages::<VoterDto>(token, &format!("issues/{}/voters", issue_id), &[])
+            .await
+    }
+
+    /// Watch/vote endpoints take no body and answer with an empty success response
+    async fn post_issue_action(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+        action: &str,
+        context: &str,
+    ) -> Result<(), TaigaClientError> {
+        let url = self.build_url(&format!("issues/{}/{}", issue_id, action))?;
+        log::info!("Sending {} for issue {} to {}", action, issue_id, url);
+
+        let req = self.client.post(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("{} response status: {}", context, response.status());
+
+        if response.status().is_success() || response.status() == StatusCode::NO_CONTENT {
+            Ok(())
+        } else {
+            Err(Self::error_from_response(response, context).await)
+        }
+    }
+}
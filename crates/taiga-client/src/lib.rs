@@ -1,18 +1,36 @@
 use reqwest::{multipart, StatusCode};
 use secrecy::{ExposeSecret, Secret};
+use serde::de::DeserializeOwned;
 use url::Url;
 
+pub mod builder;
+mod custom_attributes;
+mod epics;
 pub mod errors;
+mod issue_votes;
+mod milestones;
 pub mod models;
+pub mod pagination;
 pub mod prelude;
+mod resolver;
+pub mod retry;
+mod search;
+mod tasks;
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
+pub mod transfer;
+mod user_stories;
+mod wiki;
 
+pub use builder::TaigaClientBuilder;
 use errors::TaigaClientError;
 use models::{
     AuthDetail, IssueDetailDto, IssueDto, IssueHistoryEntryDto, LoginRequest, Me, ProjectDto,
     ProjectListEntryDto, RefreshRequest, RefreshResponse,
 };
+use pagination::{Page, PageInfo};
+use retry::RetryPolicy;
 
 const API_V1_PREFIX: &str = "api/v1/";
 
@@ -20,14 +38,27 @@ const API_V1_PREFIX: &str = "api/v1/";
 pub struct TaigaClient {
     client: reqwest::Client,
     api_base_url: Url,
+    retry_policy: RetryPolicy,
 }
 
 impl TaigaClient {
+    /// Client with the default timeouts and no proxy or extra certificates
+    /// Panics if the TLS backend cannot be initialized, like `reqwest::Client::new`.
     pub fn new(api_base_url: Url) -> Self {
-        Self {
-            client: reqwest::Client::new(),
-            api_base_url,
-        }
+        Self::builder(api_base_url)
+            .build()
+            .expect("default TaigaClient configuration is valid")
+    }
+
+    /// Start configuring timeouts, proxy, certificates and headers
+    pub fn builder(api_base_url: Url) -> TaigaClientBuilder {
+        TaigaClientBuilder::new(api_base_url)
+    }
+
+    /// Replace the retry policy used for idempotent requests
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     pub fn is_managed_url(&self, url: &str) -> bool {
@@ -36,8 +67,7 @@ impl TaigaClient {
                 (Some(target_host), Some(api_host)) => {
                     let target_lower = target_host.to_lowercase();
                     let api_lower = api_host.to_lowercase();
-                    target_lower == api_lower
-                        || target_lower.ends_with(&format!(".{}", api_lower))
+                    target_lower == api_lower || target_lower.ends_with(&format!(".{}", api_lower))
                 }
                 _ => false,
             }
@@ -57,6 +87,183 @@ impl TaigaClient {
             && (body_lower.contains("doesn't match") || body_lower.contains("does not match"))
     }
 
+    /// Send a request, retrying idempotent ones on 429, 5xx and connection errors
+    /// Honors `Retry-After` (capped at the policy's max backoff), otherwise backs off exponentially
+    async fn send(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, TaigaClientError> {
+        let mut request = req.build()?;
+        let retryable = retry::is_idempotent(request.method());
+        let mut attempt = 0;
+
+        loop {
+            let retry_request = if retryable && attempt < self.retry_policy.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let result = self.client.execute(request).await;
+
+            let Some(next_request) = retry_request else {
+                return result.map_err(Into::into);
+            };
+
+            let (delay, reason) = match &result {
+                Ok(response) if retry::is_retryable_status(response.status()) => {
+                    let delay = retry::retry_after(response.headers())
+                        .map(|d| d.min(self.retry_policy.max_backoff))
+                        .unwrap_or_else(|| self.retry_policy.backoff(attempt));
+                    (delay, response.status().to_string())
+                }
+                Err(e) if e.is_connect() || e.is_timeout() => {
+                    (self.retry_policy.backoff(attempt), e.to_string())
+                }
+                _ => return result.map_err(Into::into),
+            };
+
+            attempt += 1;
+            log::warn!(
+                "Request to {} failed ({}), retrying in {:?} (attempt {}/{})",
+                next_request.url(),
+                reason,
+                delay,
+                attempt,
+                self.retry_policy.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            request = next_request;
+        }
+    }
+
+    /// Build the error for a failed response, logging its body
+    async fn error_from_response(response: reqwest::Response, context: &str) -> TaigaClientError {
+        let status = response.status();
+        let retry_after = retry::retry_after(response.headers());
+        let body = response.text().await.unwrap_or_default();
+        log::error!("{} failed. Status: {}, Body: {}", context, status, body);
+        TaigaClientError::from_response(status, retry_after, &body)
+    }
+
+    /// Build the error for a failed PATCH of a versioned resource
+    /// Taiga returns 400 with body containing "version" error
+    /// instead of the standard 412 Precondition Failed
+    async fn patch_error_from_response(
+        response: reqwest::Response,
+        context: &str,
+    ) -> TaigaClientError {
+        let status = response.status();
+        let retry_after = retry::retry_after(response.headers());
+        let body = response.text().await.unwrap_or_default();
+        log::error!("{} failed. Status: {}, Body: {}", context, status, body);
+
+        if Self::is_version_conflict(&body) || status == StatusCode::PRECONDITION_FAILED {
+            TaigaClientError::VersionConflict(status)
+        } else {
+            TaigaClientError::from_response(status, retry_after, &body)
+        }
+    }
+
+    /// Parse a successful response body, logging the start of the body when it does not match
+    async fn parse_json<T: DeserializeOwned>(
+        response: reqwest::Response,
+        what: &str,
+    ) -> Result<T, TaigaClientError> {
+        let body = response.text().await?;
+        serde_json::from_str::<T>(&body).map_err(|e| {
+            log::error!("Failed to parse {}: {}", what, e);
+            log::error!(
+                "Raw response body (first 2000 chars): {}",
+                body_excerpt(&body, 2000)
+            );
+            TaigaClientError::Serde(e)
+        })
+    }
+
+    /// Fetch a single page of a paginated list endpoint
+    /// Taiga reports pagination through the x-paginated-count / x-pagination-next headers
+    async fn fetch_page<T: DeserializeOwned>(
+        &self,
+        token: &Secret<String>,
+        path: &str,
+        query: &[(String, String)],
+        page: u32,
+    ) -> Result<Page<T>, TaigaClientError> {
+        let url = self.build_url(path)?;
+        log::info!("Fetching page {} of {}", page, url);
+
+        let req = self
+            .client
+            .get(url)
+            .query(query)
+            .query(&[("page", page)])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Fetch page response status: {}", response.status());
+
+        if response.status().is_success() {
+            let info = PageInfo::from_headers(response.headers());
+            let body = response.text().await?;
+            match serde_json::from_str::<Vec<T>>(&body) {
+                Ok(items) => Ok(Page {
+                    items,
+                    page,
+                    total_count: info.total_count,
+                    has_next: info.has_next,
+                }),
+                Err(e) => {
+                    log::error!("Failed to parse page {} of {}: {}", page, path, e);
+                    log::error!(
+                        "Raw response body (first 2000 chars): {}",
+                        body_excerpt(&body, 2000)
+                    );
+                    Err(TaigaClientError::Serde(e))
+                }
+            }
+        } else {
+            let context = format!("Fetch page {} of {}", page, path);
+            Err(Self::error_from_response(response, &context).await)
+        }
+    }
+
+    /// Fetch every page of a paginated list endpoint and concatenate the results
+    async fn fetch_all_pages<T: DeserializeOwned>(
+        &self,
+        token: &Secret<String>,
+        path: &str,
+        query: &[(String, String)],
+    ) -> Result<Vec<T>, TaigaClientError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let current = self.fetch_page::<T>(token, path, query, page).await?;
+            let total_count = current.total_count;
+            let is_empty = current.items.is_empty();
+            items.extend(current.items);
+
+            // An empty page guards against looping forever on a misbehaving server
+            if !current.has_next || is_empty {
+                if let Some(total) = total_count {
+                    if items.len() as u64 != total {
+                        log::warn!(
+                            "Fetched {} items from {} but Taiga reported {}",
+                            items.len(),
+                            path,
+                            total
+                        );
+                    }
+                }
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(items)
+    }
+
     pub async fn login(
         &self,
         username: &str,
@@ -71,24 +278,15 @@ impl TaigaClient {
             password,
         };
 
-        let response = self.client.post(url).json(&request_body).send().await?;
+        let req = self.client.post(url).json(&request_body);
+        let response = self.send(req).await?;
         log::info!("Login response status: {}", response.status());
 
         if response.status().is_success() {
             let auth_detail = response.json::<AuthDetail>().await?;
             Ok(auth_detail)
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Login failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Login").await)
         }
     }
 
@@ -103,7 +301,8 @@ impl TaigaClient {
             refresh: refresh_token,
         };
 
-        let response = self.client.post(url).json(&request_body).send().await?;
+        let req = self.client.post(url).json(&request_body);
+        let response = self.send(req).await?;
         log::info!("Refresh token response status: {}", response.status());
 
         if response.status().is_success() {
@@ -111,9 +310,14 @@ impl TaigaClient {
             Ok(tokens)
         } else {
             let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Refresh token failed. Status: {}, Body: {}", status, body);
-            Err(TaigaClientError::Unauthorized(status))
+            // A rejected refresh token means the session is over, but transient
+            // failures must not force the user to log in again
+            match Self::error_from_response(response, "Refresh token").await {
+                err @ (TaigaClientError::RateLimited { .. } | TaigaClientError::ServerError(_)) => {
+                    Err(err)
+                }
+                _ => Err(TaigaClientError::Unauthorized(status)),
+            }
         }
     }
 
@@ -121,12 +325,8 @@ impl TaigaClient {
         let url = self.build_url("users/me")?;
         log::info!("Fetching current user from {}", url);
 
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("Get Me response status: {}", response.status());
 
@@ -134,17 +334,7 @@ impl TaigaClient {
             let me = response.json::<Me>().await?;
             Ok(me)
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Get Me failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Get Me").await)
         }
     }
 
@@ -156,12 +346,8 @@ impl TaigaClient {
         let url = self.build_url(&format!("projects/{}", project_id))?;
         log::info!("Fetching project {} from {}", project_id, url);
 
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("Get Project response status: {}", response.status());
 
@@ -173,104 +359,108 @@ impl TaigaClient {
                     log::error!(
                         "Failed to parse ProjectDto: {}. Body (first 1000 chars): {}",
                         e,
-                        &body[..body.len().min(1000)]
+                        body_excerpt(&body, 1000)
                     );
                     Err(TaigaClientError::Serde(e))
                 }
             }
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Get Project failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Get Project").await)
         }
     }
 
+    /// List projects, following every page
+    /// GET /api/v1/projects?member={member_id}
     pub async fn get_projects(
         &self,
         token: &Secret<String>,
         member_id: Option<i64>,
     ) -> Result<Vec<ProjectListEntryDto>, TaigaClientError> {
-        let url = self.build_url("projects")?;
-        log::info!(
-            "Fetching projects from {} (member_id: {:?})",
-            url,
-            member_id
-        );
-
-        let mut request = self.client.get(url).bearer_auth(token.expose_secret());
+        log::info!("Fetching projects (member_id: {:?})", member_id);
 
+        let mut query = Vec::new();
         if let Some(id) = member_id {
-            request = request.query(&[("member", id)]);
+            query.push(("member".to_string(), id.to_string()));
         }
 
-        let response = request.send().await?;
-        log::info!("Get Projects response status: {}", response.status());
-
-        if response.status().is_success() {
-            let projects = response.json::<Vec<ProjectListEntryDto>>().await?;
-            log::info!("Found {} projects", projects.len());
-            Ok(projects)
-        } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Get Projects failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
-        }
+        let projects = self
+            .fetch_all_pages::<ProjectListEntryDto>(token, "projects", &query)
+            .await?;
+        log::info!("Found {} projects", projects.len());
+        Ok(projects)
     }
 
+    /// List every issue of a project matching the given filters, following every page
+    /// GET /api/v1/issues?project={project_id}
     pub async fn list_issues(
         &self,
         token: &Secret<String>,
         project_id: i64,
         filters: Option<Vec<(String, String)>>,
     ) -> Result<Vec<IssueDto>, TaigaClientError> {
-        let url = self.build_url("issues")?;
-        log::info!("Fetching issues from {} for project {}", url, project_id);
+        log::info!("Fetching issues for project {}", project_id);
+
+        let query = Self::issue_list_query(project_id, filters);
+        let issues = self
+            .fetch_all_pages::<IssueDto>(token, "issues", &query)
+            .await?;
+        log::info!("Found {} issues in project {}", issues.len(), project_id);
+        Ok(issues)
+    }
+
+    fn issue_list_query(
+        project_id: i64,
+        filters: Option<Vec<(String, String)>>,
+    ) -> Vec<(String, String)> {
+        let mut query = vec![("project".to_string(), project_id.to_string())];
+        query.extend(filters.unwrap_or_default());
+        query
+    }
+
+    /// Fetch per-option issue counts for a project's filter facets
+    /// Takes the same filters as `list_issues`, so counts reflect the other active filters
+    /// GET /api/v1/issues/filters_data?project={project_id}
+    pub async fn get_issue_filters_data(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        filters: Option<Vec<(String, String)>>,
+    ) -> Result<models::IssueFiltersDataDto, TaigaClientError> {
+        let url = self.build_url("issues/filters_data")?;
+        log::info!("Fetching issue filters data for project {}", project_id);
 
-        let mut request = self
+        let req = self
             .client
             .get(url)
-            .query(&[("project", project_id.to_string())])
+            .query(&Self::issue_list_query(project_id, filters))
             .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
-        if let Some(f) = filters {
-            request = request.query(&f);
+        if response.status().is_success() {
+            Self::parse_json(response, "issue filters data").await
+        } else {
+            Err(Self::error_from_response(response, "Get issue filters data").await)
         }
+    }
 
-        let response = request.send().await?;
+    /// Fetch issue counts of a project by status, priority, severity and assignee
+    /// Also includes open and closed counts for each of the last four weeks' days.
+    /// GET /api/v1/projects/{project_id}/issues_stats
+    pub async fn get_project_issues_stats(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+    ) -> Result<models::ProjectIssuesStatsDto, TaigaClientError> {
+        let url = self.build_url(&format!("projects/{}/issues_stats", project_id))?;
+        log::info!("Fetching issue stats for project {}", project_id);
 
-        log::info!("List Issues response status: {}", response.status());
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
-            let issues = response.json::<Vec<IssueDto>>().await?;
-            Ok(issues)
+            Self::parse_json(response, "project issue stats").await
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("List Issues failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Get project issue stats").await)
         }
     }
 
@@ -284,12 +474,8 @@ impl TaigaClient {
         let url = self.build_url(&format!("issues/{}", issue_id))?;
         log::info!("Fetching issue detail {} from {}", issue_id, url);
 
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("Get Issue response status: {}", response.status());
 
@@ -304,75 +490,31 @@ impl TaigaClient {
                     log::error!("Failed to parse issue detail: {}", e);
                     log::error!(
                         "Raw response body (first 2000 chars): {}",
-                        &body[..body.len().min(2000)]
+                        body_excerpt(&body, 2000)
                     );
                     Err(TaigaClientError::Serde(e))
                 }
             }
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Get Issue failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Get Issue").await)
         }
     }
 
-    /// Fetch issue history (comments and changes)
+    /// Fetch issue history (comments and changes), following every page
     /// GET /api/v1/history/issue/{issue_id}
     pub async fn get_issue_history(
         &self,
         token: &Secret<String>,
         issue_id: i64,
     ) -> Result<Vec<IssueHistoryEntryDto>, TaigaClientError> {
-        let url = self.build_url(&format!("history/issue/{}", issue_id))?;
-        log::info!("Fetching issue history {} from {}", issue_id, url);
-
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
-
-        log::info!("Get Issue History response status: {}", response.status());
-
-        if response.status().is_success() {
-            let body = response.text().await?;
-            match serde_json::from_str::<Vec<IssueHistoryEntryDto>>(&body) {
-                Ok(history) => Ok(history),
-                Err(e) => {
-                    log::error!("Failed to parse issue history: {}", e);
-                    log::error!(
-                        "Raw response body (first 2000 chars): {}",
-                        &body[..body.len().min(2000)]
-                    );
-                    Err(TaigaClientError::Serde(e))
-                }
-            }
-        } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!(
-                "Get Issue History failed. Status: {}, Body: {}",
-                status,
-                body
-            );
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
-        }
+        log::info!("Fetching issue history {}", issue_id);
+
+        self.fetch_all_pages::<IssueHistoryEntryDto>(
+            token,
+            &format!("history/issue/{}", issue_id),
+            &[],
+        )
+        .await
     }
 
     /// Patch an issue (e.g., change status)
@@ -392,13 +534,12 @@ impl TaigaClient {
             request.version
         );
 
-        let response = self
+        let req = self
             .client
             .patch(url)
             .bearer_auth(token.expose_secret())
-            .json(&request)
-            .send()
-            .await?;
+            .json(&request);
+        let response = self.send(req).await?;
 
         log::info!("Patch Issue response status: {}", response.status());
 
@@ -410,33 +551,135 @@ impl TaigaClient {
                     log::error!("Failed to parse patched issue: {}", e);
                     log::error!(
                         "Raw response body (first 2000 chars): {}",
-                        &body[..body.len().min(2000)]
+                        body_excerpt(&body, 2000)
                     );
                     Err(TaigaClientError::Serde(e))
                 }
             }
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Patch Issue failed. Status: {}, Body: {}", status, body);
+            Err(Self::patch_error_from_response(response, "Patch Issue").await)
+        }
+    }
 
-            // Taiga returns 400 with body containing "version" error
-            // instead of the standard 412 Precondition Failed
-            let is_conflict =
-                Self::is_version_conflict(&body) || status == StatusCode::PRECONDITION_FAILED;
+    /// Create a new issue
+    /// POST /api/v1/issues
+    pub async fn create_issue(
+        &self,
+        token: &Secret<String>,
+        request: models::CreateIssueRequest,
+    ) -> Result<IssueDto, TaigaClientError> {
+        let url = self.build_url("issues")?;
+        log::info!("Creating issue in project {} at {}", request.project, url);
 
-            let err = if is_conflict {
-                TaigaClientError::VersionConflict(status)
-            } else {
-                match status {
-                    StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                        TaigaClientError::Unauthorized(status)
-                    }
-                    _ => TaigaClientError::AuthFailed(status),
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Create Issue response status: {}", response.status());
+
+        if response.status().is_success() {
+            let body = response.text().await?;
+            match serde_json::from_str::<IssueDto>(&body) {
+                Ok(issue) => {
+                    log::info!("Created issue {}", issue.id);
+                    Ok(issue)
                 }
-            };
-            Err(err)
+                Err(e) => {
+                    log::error!("Failed to parse created issue: {}", e);
+                    log::error!(
+                        "Raw response body (first 2000 chars): {}",
+                        body_excerpt(&body, 2000)
+                    );
+                    Err(TaigaClientError::Serde(e))
+                }
+            }
+        } else {
+            Err(Self::error_from_response(response, "Create Issue").await)
+        }
+    }
+
+    /// Create one issue per subject with the project's default status, type,
+    /// priority and severity; issues come back in the order of the subjects
+    /// POST /api/v1/issues/bulk_create
+    pub async fn bulk_create_issues(
+        &self,
+        token: &Secret<String>,
+        request: models::BulkCreateIssuesRequest,
+    ) -> Result<Vec<IssueDto>, TaigaClientError> {
+        let url = self.build_url("issues/bulk_create")?;
+        log::info!(
+            "Bulk creating {} issue(s) in project {} at {}",
+            request.bulk_issues.lines().count(),
+            request.project_id,
+            url
+        );
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Bulk Create Issues response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "bulk created issues").await
+        } else {
+            Err(Self::error_from_response(response, "Bulk Create Issues").await)
+        }
+    }
+
+    /// Turn an issue into a user story; the issue itself is left untouched
+    /// Returns the ref numbers of the created user stories.
+    /// POST /api/v1/issues/{issue_id}/promote_to_user_story
+    pub async fn promote_issue_to_user_story(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+        project_id: i64,
+    ) -> Result<Vec<i64>, TaigaClientError> {
+        let url = self.build_url(&format!("issues/{}/promote_to_user_story", issue_id))?;
+        log::info!("Promoting issue {} to a user story", issue_id);
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&models::PromoteToUserStoryRequest { project_id });
+        let response = self.send(req).await?;
+
+        log::info!("Promote Issue response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "promoted user story refs").await
+        } else {
+            Err(Self::error_from_response(response, "Promote Issue").await)
+        }
+    }
+
+    /// Delete an issue
+    /// DELETE /api/v1/issues/{issue_id}
+    pub async fn delete_issue(
+        &self,
+        token: &Secret<String>,
+        issue_id: i64,
+    ) -> Result<(), TaigaClientError> {
+        let url = self.build_url(&format!("issues/{}", issue_id))?;
+        log::info!("Deleting issue {} at {}", issue_id, url);
+
+        let req = self.client.delete(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Delete Issue response status: {}", response.status());
+
+        if response.status().is_success() || response.status() == StatusCode::NO_CONTENT {
+            Ok(())
+        } else {
+            Err(Self::error_from_response(response, "Delete Issue").await)
         }
     }
 
@@ -456,16 +699,15 @@ impl TaigaClient {
             url
         );
 
-        let response = self
+        let req = self
             .client
             .get(url)
             .query(&[
                 ("project", project_id.to_string()),
                 ("object_id", issue_id.to_string()),
             ])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("List attachments response status: {}", response.status());
 
@@ -481,27 +723,13 @@ impl TaigaClient {
                     log::error!("Failed to parse attachments response: {}", e);
                     log::error!(
                         "Raw response body (first 2000 chars): {}",
-                        &body[..body.len().min(2000)]
+                        body_excerpt(&body, 2000)
                     );
                     Err(TaigaClientError::Serde(e))
                 }
             }
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!(
-                "List attachments failed. Status: {}, Body: {}",
-                status,
-                body
-            );
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "List attachments").await)
         }
     }
 
@@ -540,13 +768,12 @@ impl TaigaClient {
 
         log::info!("[Debug] Sending multipart request...");
 
-        let response = self
+        let req = self
             .client
             .post(url)
             .bearer_auth(token.expose_secret())
-            .multipart(form)
-            .send()
-            .await?;
+            .multipart(form);
+        let response = self.send(req).await?;
 
         log::info!(
             "[Debug] Upload attachment response status: {}",
@@ -561,21 +788,7 @@ impl TaigaClient {
             );
             Ok(attachment)
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!(
-                "[Debug] Upload attachment failed. Status: {}, Body: {}",
-                status,
-                body
-            );
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Upload attachment").await)
         }
     }
 
@@ -587,33 +800,15 @@ impl TaigaClient {
         let url = self.build_url(&format!("issues/attachments/{}", attachment_id))?;
         log::info!("Deleting attachment {}", attachment_id);
 
-        let response = self
-            .client
-            .delete(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+        let req = self.client.delete(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("Delete attachment response status: {}", response.status());
 
         if response.status().is_success() || response.status() == StatusCode::NO_CONTENT {
             Ok(())
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!(
-                "Delete attachment failed. Status: {}, Body: {}",
-                status,
-                body
-            );
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Delete attachment").await)
         }
     }
 
@@ -631,7 +826,7 @@ impl TaigaClient {
             }
         }
 
-        let response = req.send().await?;
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             let content_type = response
@@ -643,7 +838,7 @@ impl TaigaClient {
             let bytes = response.bytes().await?.to_vec();
             Ok((bytes, content_type))
         } else {
-            Err(TaigaClientError::AuthFailed(response.status()))
+            Err(Self::error_from_response(response, "Get raw resource").await)
         }
     }
 
@@ -655,20 +850,17 @@ impl TaigaClient {
         let url = self.build_url("priorities")?;
         log::info!("Fetching priorities for project {}", project_id);
 
-        let response = self
+        let req = self
             .client
             .get(url)
             .query(&[("project", project_id)])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
         } else {
-            let status = response.status();
-            log::error!("Get priorities failed. Status: {}", status);
-            Err(TaigaClientError::AuthFailed(status))
+            Err(Self::error_from_response(response, "Get priorities").await)
         }
     }
 
@@ -680,20 +872,17 @@ impl TaigaClient {
         let url = self.build_url("severities")?;
         log::info!("Fetching severities for project {}", project_id);
 
-        let response = self
+        let req = self
             .client
             .get(url)
             .query(&[("project", project_id)])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
         } else {
-            let status = response.status();
-            log::error!("Get severities failed. Status: {}", status);
-            Err(TaigaClientError::AuthFailed(status))
+            Err(Self::error_from_response(response, "Get severities").await)
         }
     }
 
@@ -705,20 +894,17 @@ impl TaigaClient {
         let url = self.build_url("issue-types")?;
         log::info!("Fetching issue types for project {}", project_id);
 
-        let response = self
+        let req = self
             .client
             .get(url)
             .query(&[("project", project_id)])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
         } else {
-            let status = response.status();
-            log::error!("Get issue types failed. Status: {}", status);
-            Err(TaigaClientError::AuthFailed(status))
+            Err(Self::error_from_response(response, "Get issue types").await)
         }
     }
 
@@ -730,19 +916,13 @@ impl TaigaClient {
         let url = self.build_url(&format!("projects/{}/tags_colors", project_id))?;
         log::info!("Fetching tags colors for project {}", project_id);
 
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
         } else {
-            let status = response.status();
-            log::error!("Get tags colors failed. Status: {}", status);
-            Err(TaigaClientError::AuthFailed(status))
+            Err(Self::error_from_response(response, "Get tags colors").await)
         }
     }
 
@@ -754,13 +934,12 @@ impl TaigaClient {
         let url = self.build_url("memberships")?;
         log::info!("Fetching memberships for project {}", project_id);
 
-        let response = self
+        let req = self
             .client
             .get(url)
             .query(&[("project", project_id)])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             let body = response.text().await?;
@@ -770,15 +949,13 @@ impl TaigaClient {
                     log::error!(
                         "Failed to parse memberships: {}. Body (first 500 chars): {}",
                         e,
-                        &body[..body.len().min(500)]
+                        body_excerpt(&body, 500)
                     );
                     Err(TaigaClientError::Serde(e))
                 }
             }
         } else {
-            let status = response.status();
-            log::error!("Get memberships failed. Status: {}", status);
-            Err(TaigaClientError::AuthFailed(status))
+            Err(Self::error_from_response(response, "Get memberships").await)
         }
     }
 
@@ -801,31 +978,20 @@ impl TaigaClient {
 
         let body = serde_json::json!({ "comment": new_comment });
 
-        let response = self
+        let req = self
             .client
             .post(url)
             .query(&[("id", comment_id)])
             .bearer_auth(token.expose_secret())
-            .json(&body)
-            .send()
-            .await?;
+            .json(&body);
+        let response = self.send(req).await?;
 
         log::info!("Edit comment response status: {}", response.status());
 
         if response.status().is_success() {
             Ok(())
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!("Edit comment failed. Status: {}, Body: {}", status, body);
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Edit comment").await)
         }
     }
 
@@ -845,34 +1011,29 @@ impl TaigaClient {
             url
         );
 
-        let response = self
+        let req = self
             .client
             .post(url)
             .query(&[("id", comment_id)])
-            .bearer_auth(token.expose_secret())
-            .send()
-            .await?;
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
 
         log::info!("Delete comment response status: {}", response.status());
 
         if response.status().is_success() || response.status() == StatusCode::NO_CONTENT {
             Ok(())
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log::error!(
-                "Delete comment failed. Status: {}, Body: {}",
-                status,
-                body
-            );
-            let err = match status {
-                StatusCode::NOT_FOUND => TaigaClientError::EndpointNotFound(status),
-                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
-                    TaigaClientError::Unauthorized(status)
-                }
-                _ => TaigaClientError::AuthFailed(status),
-            };
-            Err(err)
+            Err(Self::error_from_response(response, "Delete comment").await)
         }
     }
 }
+
+/// At most `max_len` bytes from the start of a response body, for logging
+/// Cuts on a character boundary, so localized error pages cannot cause a panic.
+fn body_excerpt(body: &str, max_len: usize) -> &str {
+    let mut end = body.len().min(max_len);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    &body[..end]
+}
@@ -0,0 +1,59 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{MilestoneDto, MilestoneStatsDto};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List every milestone (sprint) of a project, following every page
+    /// GET /api/v1/milestones?project={project_id}
+    pub async fn list_milestones(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        closed: Option<bool>,
+    ) -> Result<Vec<MilestoneDto>, TaigaClientError> {
+        log::info!(
+            "Fetching milestones for project {} (closed: {:?})",
+            project_id,
+            closed
+        );
+
+        let mut query = vec![("project".to_string(), project_id.to_string())];
+        if let Some(closed) = closed {
+            query.push(("closed".to_string(), closed.to_string()));
+        }
+
+        let milestones = self
+            .fetch_all_pages::<MilestoneDto>(token, "milestones", &query)
+            .await?;
+        log::info!(
+            "Found {} milestones in project {}",
+            milestones.len(),
+            project_id
+        );
+        Ok(milestones)
+    }
+
+    /// Fetch progress statistics and burndown for a milestone
+    /// GET /api/v1/milestones/{milestone_id}/stats
+    pub async fn get_milestone_stats(
+        &self,
+        token: &Secret<String>,
+        milestone_id: i64,
+    ) -> Result<MilestoneStatsDto, TaigaClientError> {
+        let url = self.build_url(&format!("milestones/{}/stats", milestone_id))?;
+        log::info!("Fetching milestone {} stats from {}", milestone_id, url);
+
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Get Milestone Stats response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "milestone stats").await
+        } else {
+            Err(Self::error_from_response(response, "Get Milestone Stats").await)
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
@@ -53,6 +55,8 @@ pub struct IssueStatusDto {
     pub name: String,
     pub color: String,
     pub is_closed: bool,
+    #[serde(default)]
+    pub order: i64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -89,6 +93,12 @@ pub struct ProjectDto {
     pub severities: Option<Vec<SeverityDto>>,
     #[serde(default)]
     pub issue_types: Option<Vec<IssueTypeDto>>,
+    /// User story statuses share the issue status shape
+    #[serde(default)]
+    pub us_statuses: Option<Vec<IssueStatusDto>>,
+    /// Task statuses share the issue status shape
+    #[serde(default)]
+    pub task_statuses: Option<Vec<IssueStatusDto>>,
     #[serde(default)]
     pub tags_colors: serde_json::Value,
 }
@@ -122,6 +132,8 @@ pub struct UserExtraInfo {
 #[derive(Debug, Clone, Deserialize)]
 pub struct IssueDto {
     pub id: i64,
+    #[serde(rename = "ref", default)]
+    pub ref_: Option<i64>,
     pub subject: String,
     pub project: i64,
     pub status: i64,
@@ -136,6 +148,10 @@ pub struct IssueDto {
     pub severity: Option<i64>,
     #[serde(rename = "type", default)]
     pub type_: Option<i64>,
+    #[serde(default)]
+    pub milestone: Option<i64>,
+    #[serde(default)]
+    pub version: Option<i64>,
 }
 
 // ============================================================================
@@ -212,6 +228,12 @@ pub struct IssueDetailDto {
     #[serde(default)]
     pub total_watchers: i64,
     #[serde(default)]
+    pub is_watcher: bool,
+    #[serde(default)]
+    pub total_voters: i64,
+    #[serde(default)]
+    pub is_voter: bool,
+    #[serde(default)]
     pub is_closed: bool,
     #[serde(default)]
     pub is_blocked: bool,
@@ -221,10 +243,21 @@ pub struct IssueDetailDto {
     pub created_date: String,
     pub modified_date: String,
     pub finished_date: Option<String>,
+    #[serde(default)]
+    pub milestone: Option<i64>,
     pub version: i64,
     pub neighbors: Option<IssueNeighbors>,
 }
 
+/// Voter entry from GET /api/v1/issues/{id}/voters
+#[derive(Debug, Clone, Deserialize)]
+pub struct VoterDto {
+    pub id: i64,
+    pub username: String,
+    #[serde(default)]
+    pub full_name: Option<String>,
+}
+
 // ============================================================================
 // Issue History DTOs (GET /api/v1/history/issue/{id})
 // ============================================================================
@@ -265,6 +298,550 @@ pub struct IssueHistoryEntryDto {
     pub is_hidden: Option<bool>,
 }
 
+// ============================================================================
+// User Story DTOs (GET /api/v1/userstories, GET /api/v1/userstories/{id})
+// ============================================================================
+
+/// User story list entry from GET /api/v1/userstories
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserStoryDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub project: i64,
+    pub status: i64,
+    pub status_extra_info: Option<IssueStatusExtraInfo>,
+    pub owner: Option<i64>,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_extra_info: Option<UserExtraInfo>,
+    pub modified_date: Option<String>,
+    #[serde(default)]
+    pub total_points: Option<f64>,
+    #[serde(default)]
+    pub milestone: Option<i64>,
+    #[serde(default)]
+    pub is_closed: bool,
+    #[serde(default)]
+    pub is_blocked: bool,
+}
+
+/// Full user story detail from GET /api/v1/userstories/{id}
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserStoryDetailDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub description: Option<String>,
+    pub description_html: Option<String>,
+    pub project: i64,
+    pub project_extra_info: ProjectExtraInfo,
+    pub status: i64,
+    pub status_extra_info: Option<IssueStatusExtraInfo>,
+    pub owner: Option<i64>,
+    pub owner_extra_info: Option<UserExtraInfo>,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_extra_info: Option<UserExtraInfo>,
+    #[serde(default)]
+    pub tags: serde_json::Value, // [[name, color|null], ...] - color can be null
+    #[serde(default)]
+    pub attachments: Vec<AttachmentDto>,
+    #[serde(default)]
+    pub watchers: Vec<i64>,
+    #[serde(default)]
+    pub total_watchers: i64,
+    #[serde(default)]
+    pub total_points: Option<f64>,
+    #[serde(default)]
+    pub client_requirement: bool,
+    #[serde(default)]
+    pub team_requirement: bool,
+    #[serde(default)]
+    pub is_closed: bool,
+    #[serde(default)]
+    pub is_blocked: bool,
+    pub blocked_note: Option<String>,
+    pub due_date: Option<String>,
+    pub due_date_status: Option<String>,
+    pub created_date: String,
+    pub modified_date: String,
+    pub finish_date: Option<String>,
+    #[serde(default)]
+    pub milestone: Option<i64>,
+    pub version: i64,
+    pub neighbors: Option<IssueNeighbors>,
+}
+
+/// Request body for patching a user story
+/// Same optimistic locking and omission rules as `PatchIssueRequest`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PatchUserStoryRequest {
+    pub version: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// `Some(None)` unassigns the story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<Option<i64>>,
+    /// Tags as [[name, color|null], ...]; replaces all existing tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<serde_json::Value>,
+}
+
+// ============================================================================
+// Task DTOs (GET /api/v1/tasks, GET /api/v1/tasks/{id})
+// ============================================================================
+
+/// Parent user story summary embedded in task responses
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserStoryExtraInfo {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+}
+
+/// Task list entry from GET /api/v1/tasks
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub project: i64,
+    pub user_story: Option<i64>,
+    pub user_story_extra_info: Option<UserStoryExtraInfo>,
+    pub status: i64,
+    pub status_extra_info: Option<IssueStatusExtraInfo>,
+    pub owner: Option<i64>,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_extra_info: Option<UserExtraInfo>,
+    pub modified_date: Option<String>,
+    #[serde(default)]
+    pub is_closed: bool,
+    #[serde(default)]
+    pub is_blocked: bool,
+}
+
+/// Full task detail from GET /api/v1/tasks/{id}
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskDetailDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub description: Option<String>,
+    pub description_html: Option<String>,
+    pub project: i64,
+    pub project_extra_info: ProjectExtraInfo,
+    pub user_story: Option<i64>,
+    pub user_story_extra_info: Option<UserStoryExtraInfo>,
+    pub status: i64,
+    pub status_extra_info: Option<IssueStatusExtraInfo>,
+    pub owner: Option<i64>,
+    pub owner_extra_info: Option<UserExtraInfo>,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_extra_info: Option<UserExtraInfo>,
+    #[serde(default)]
+    pub tags: serde_json::Value, // [[name, color|null], ...] - color can be null
+    #[serde(default)]
+    pub attachments: Vec<AttachmentDto>,
+    #[serde(default)]
+    pub watchers: Vec<i64>,
+    #[serde(default)]
+    pub is_closed: bool,
+    #[serde(default)]
+    pub is_blocked: bool,
+    pub blocked_note: Option<String>,
+    #[serde(default)]
+    pub is_iocaine: bool,
+    pub due_date: Option<String>,
+    pub created_date: String,
+    pub modified_date: String,
+    pub finished_date: Option<String>,
+    pub version: i64,
+}
+
+/// Request body for patching a task
+/// Same optimistic locking and omission rules as `PatchIssueRequest`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PatchTaskRequest {
+    pub version: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// `Some(None)` unassigns the task
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<Option<i64>>,
+    /// `Some(None)` detaches the task from its user story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_story: Option<Option<i64>>,
+}
+
+/// Request body for creating a task (POST /api/v1/tasks)
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateTaskRequest {
+    pub project: i64,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_story: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<i64>,
+}
+
+// ============================================================================
+// Epic DTOs (GET /api/v1/epics, /api/v1/epics/{id}/related_userstories)
+// ============================================================================
+
+/// Epic list entry from GET /api/v1/epics
+#[derive(Debug, Clone, Deserialize)]
+pub struct EpicDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub project: i64,
+    pub color: Option<String>,
+    pub status: i64,
+    pub status_extra_info: Option<IssueStatusExtraInfo>,
+    pub owner: Option<i64>,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_extra_info: Option<UserExtraInfo>,
+    pub modified_date: Option<String>,
+    #[serde(default)]
+    pub is_closed: bool,
+}
+
+/// Link between an epic and a user story
+#[derive(Debug, Clone, Deserialize)]
+pub struct EpicRelatedUserStoryDto {
+    pub epic: i64,
+    pub user_story: i64,
+    #[serde(default)]
+    pub order: i64,
+}
+
+/// Request body for linking a user story to an epic
+/// (POST /api/v1/epics/{id}/related_userstories)
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkEpicUserStoryRequest {
+    pub epic: i64,
+    pub user_story: i64,
+}
+
+// ============================================================================
+// Milestone DTOs (GET /api/v1/milestones, GET /api/v1/milestones/{id}/stats)
+// ============================================================================
+
+/// Milestone (sprint) from GET /api/v1/milestones
+/// Dates are plain `YYYY-MM-DD` strings
+#[derive(Debug, Clone, Deserialize)]
+pub struct MilestoneDto {
+    pub id: i64,
+    pub name: String,
+    pub slug: String,
+    pub project: i64,
+    pub estimated_start: String,
+    pub estimated_finish: String,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub total_points: Option<f64>,
+    #[serde(default)]
+    pub closed_points: Option<f64>,
+    pub created_date: Option<String>,
+    pub modified_date: Option<String>,
+}
+
+/// Burndown point of a milestone
+#[derive(Debug, Clone, Deserialize)]
+pub struct MilestoneDayDto {
+    pub day: String,
+    #[serde(default)]
+    pub open_points: Option<f64>,
+    #[serde(default)]
+    pub optimal_points: Option<f64>,
+}
+
+/// Sprint statistics from GET /api/v1/milestones/{id}/stats
+#[derive(Debug, Clone, Deserialize)]
+pub struct MilestoneStatsDto {
+    pub name: String,
+    pub estimated_start: String,
+    pub estimated_finish: String,
+    /// Points per role, as `{role_id: points}`
+    #[serde(default)]
+    pub total_points: serde_json::Value,
+    /// Completed points per role; Taiga sends a list or a map depending on the version
+    #[serde(default)]
+    pub completed_points: serde_json::Value,
+    #[serde(default)]
+    pub total_userstories: i64,
+    #[serde(default)]
+    pub completed_userstories: i64,
+    #[serde(default)]
+    pub total_tasks: i64,
+    #[serde(default)]
+    pub completed_tasks: i64,
+    #[serde(default)]
+    pub iocaine_doses: i64,
+    #[serde(default)]
+    pub days: Vec<MilestoneDayDto>,
+}
+
+// ============================================================================
+// Filter Facet DTOs (GET /api/v1/issues/filters_data)
+// ============================================================================
+
+/// Status, type, priority or severity option with the number of matching issues
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterOptionDto {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub order: i64,
+    pub count: i64,
+}
+
+/// Assignee or owner option; `id` is null for unassigned issues
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterUserDto {
+    pub id: Option<i64>,
+    #[serde(default)]
+    pub full_name: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterTagDto {
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    pub count: i64,
+}
+
+/// Facet counts for a project's issues from GET /api/v1/issues/filters_data
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueFiltersDataDto {
+    #[serde(default)]
+    pub statuses: Vec<FilterOptionDto>,
+    #[serde(default)]
+    pub types: Vec<FilterOptionDto>,
+    #[serde(default)]
+    pub priorities: Vec<FilterOptionDto>,
+    #[serde(default)]
+    pub severities: Vec<FilterOptionDto>,
+    #[serde(default)]
+    pub assigned_to: Vec<FilterUserDto>,
+    #[serde(default)]
+    pub owners: Vec<FilterUserDto>,
+    #[serde(default)]
+    pub tags: Vec<FilterTagDto>,
+}
+
+// ============================================================================
+// Issue Stats DTOs (GET /api/v1/projects/{id}/issues_stats)
+// ============================================================================
+
+/// One bucket of the issue stats, e.g. a priority or an assignee
+/// Taiga reports unassigned issues under ID 0.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueStatsEntryDto {
+    #[serde(default)]
+    pub id: Option<i64>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub count: i64,
+}
+
+/// Open and closed issue counts for each of the last 28 days, oldest first
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IssueOpenClosedDaysDto {
+    #[serde(default)]
+    pub open: Vec<i64>,
+    #[serde(default)]
+    pub closed: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IssueStatsDaysDto {
+    #[serde(default)]
+    pub by_open_closed: IssueOpenClosedDaysDto,
+}
+
+/// Buckets are keyed by the option or user ID as a string
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectIssuesStatsDto {
+    #[serde(default)]
+    pub total_issues: i64,
+    #[serde(default)]
+    pub opened_issues: i64,
+    #[serde(default)]
+    pub closed_issues: i64,
+    #[serde(default)]
+    pub issues_per_status: HashMap<String, IssueStatsEntryDto>,
+    #[serde(default)]
+    pub issues_per_type: HashMap<String, IssueStatsEntryDto>,
+    #[serde(default)]
+    pub issues_per_priority: HashMap<String, IssueStatsEntryDto>,
+    #[serde(default)]
+    pub issues_per_severity: HashMap<String, IssueStatsEntryDto>,
+    #[serde(default)]
+    pub issues_per_assigned_to: HashMap<String, IssueStatsEntryDto>,
+    #[serde(default)]
+    pub last_four_weeks_days: IssueStatsDaysDto,
+}
+
+// ============================================================================
+// Search DTOs (GET /api/v1/search)
+// ============================================================================
+
+/// Search hit for an issue or user story; Taiga sends only these fields
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchItemDto {
+    pub id: i64,
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub subject: String,
+    pub status: i64,
+    #[serde(default)]
+    pub assigned_to: Option<i64>,
+}
+
+/// Results of GET /api/v1/search, ordered by relevance within each kind
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResultsDto {
+    #[serde(default)]
+    pub count: i64,
+    #[serde(default)]
+    pub issues: Vec<SearchItemDto>,
+    #[serde(default)]
+    pub userstories: Vec<SearchItemDto>,
+}
+
+/// IDs returned by GET /api/v1/resolver
+/// Only the key matching the looked-up ref is present besides `project`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResolverDto {
+    pub project: i64,
+    pub issue: Option<i64>,
+    pub us: Option<i64>,
+    pub task: Option<i64>,
+    pub epic: Option<i64>,
+}
+
+// ============================================================================
+// Wiki DTOs (GET /api/v1/wiki, GET /api/v1/wiki-links)
+// ============================================================================
+
+/// Wiki page from GET /api/v1/wiki and GET /api/v1/wiki/{id}
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiPageDto {
+    pub id: i64,
+    pub project: i64,
+    pub slug: String,
+    #[serde(default)]
+    pub content: String,
+    /// Rendered content; only sent on detail responses
+    #[serde(default)]
+    pub html: Option<String>,
+    pub owner: Option<i64>,
+    pub last_modifier: Option<i64>,
+    pub created_date: Option<String>,
+    pub modified_date: Option<String>,
+    #[serde(default)]
+    pub editions: i64,
+    pub version: i64,
+}
+
+/// Sidebar link to a wiki page from GET /api/v1/wiki-links
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiLinkDto {
+    pub id: i64,
+    pub project: i64,
+    pub title: String,
+    pub href: String,
+    #[serde(default)]
+    pub order: i64,
+}
+
+/// Request body for creating a wiki page (POST /api/v1/wiki)
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateWikiPageRequest {
+    pub project: i64,
+    pub slug: String,
+    pub content: String,
+}
+
+/// Request body for editing a wiki page (PATCH /api/v1/wiki/{id})
+#[derive(Debug, Clone, Serialize)]
+pub struct PatchWikiPageRequest {
+    /// The version field for optimistic locking (required by Taiga)
+    pub version: i64,
+    pub content: String,
+}
+
+// ============================================================================
+// Custom Attribute DTOs (GET /api/v1/issue-custom-attributes)
+// ============================================================================
+
+/// Custom attribute definition from GET /api/v1/issue-custom-attributes?project={id}
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomAttributeDto {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// "text", "multiline", "richtext", "date", "url", "dropdown", "checkbox" or "number"
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub order: i64,
+    pub project: i64,
+    /// Dropdown options; null for other types
+    #[serde(default)]
+    pub extra: serde_json::Value,
+}
+
+/// Custom attribute values of an issue from GET /api/v1/issues/custom-attributes-values/{id}
+/// Keys of `attributes_values` are attribute IDs as strings
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueCustomAttributesValuesDto {
+    #[serde(default)]
+    pub attributes_values: serde_json::Map<String, serde_json::Value>,
+    pub version: i64,
+    pub issue: i64,
+}
+
+/// Request body for PATCH /api/v1/issues/custom-attributes-values/{id}
+/// Taiga replaces the whole map, so it must carry every value to keep
+#[derive(Debug, Clone, Serialize)]
+pub struct PatchIssueCustomAttributesValuesRequest {
+    /// The version field for optimistic locking (required by Taiga)
+    pub version: i64,
+    pub attributes_values: serde_json::Map<String, serde_json::Value>,
+}
+
 // ============================================================================
 // Project Metadata DTOs (Type, Priority, Severity)
 // ============================================================================
@@ -354,3 +931,48 @@ pub struct PatchIssueRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
 }
+
+// ============================================================================
+// Issue Create Request DTOs (POST /api/v1/issues)
+// ============================================================================
+
+/// Request body for creating an issue
+/// Optional fields are omitted so Taiga applies the project defaults
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateIssueRequest {
+    pub project: i64,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<i64>,
+    /// Tags as [name, color|null] pairs
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<(String, Option<String>)>,
+    /// User IDs to add as watchers
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub watchers: Vec<i64>,
+}
+
+/// Request body for POST /api/v1/issues/bulk_create
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkCreateIssuesRequest {
+    pub project_id: i64,
+    /// One issue subject per line
+    pub bulk_issues: String,
+}
+
+/// Request body for POST /api/v1/issues/{id}/promote_to_user_story
+#[derive(Debug, Clone, Serialize)]
+pub struct PromoteToUserStoryRequest {
+    /// Project that receives the new user story
+    pub project_id: i64,
+}
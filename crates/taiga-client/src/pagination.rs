@@ -0,0 +1,40 @@
+use reqwest::header::HeaderMap;
+
+const PAGINATED_COUNT_HEADER: &str = "x-paginated-count";
+const PAGINATION_NEXT_HEADER: &str = "x-pagination-next";
+
+/// A single page of results from a paginated Taiga list endpoint
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// 1-based page number that was returned
+    pub page: u32,
+    /// Total number of items across all pages (`x-paginated-count`), if reported
+    pub total_count: Option<u64>,
+    /// Whether Taiga advertised a following page (`x-pagination-next`)
+    pub has_next: bool,
+}
+
+/// Pagination metadata read from Taiga response headers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct PageInfo {
+    pub total_count: Option<u64>,
+    pub has_next: bool,
+}
+
+impl PageInfo {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let header_str = |name: &str| headers.get(name).and_then(|h| h.to_str().ok());
+
+        let total_count = header_str(PAGINATED_COUNT_HEADER).and_then(|v| v.trim().parse().ok());
+        // Unpaginated responses omit the header; the last page sends it empty
+        let has_next = header_str(PAGINATION_NEXT_HEADER)
+            .map(|v| !v.trim().is_empty())
+            .unwrap_or(false);
+
+        Self {
+            total_count,
+            has_next,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{IssueDetailDto, ResolverDto};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// Turn a project slug, and optionally a ref number within it, into IDs
+    /// GET /api/v1/resolver?project={slug}&ref={ref}
+    pub async fn resolve(
+        &self,
+        token: &Secret<String>,
+        project_slug: &str,
+        ref_: Option<i64>,
+    ) -> Result<ResolverDto, TaigaClientError> {
+        let url = self.build_url("resolver")?;
+        log::info!("Resolving project '{}' ref {:?}", project_slug, ref_);
+
+        let mut query = vec![("project", project_slug.to_string())];
+        if let Some(r) = ref_ {
+            query.push(("ref", r.to_string()));
+        }
+
+        let req = self
+            .client
+            .get(url)
+            .query(&query)
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Resolver response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "resolver result").await
+        } else {
+            Err(Self::error_from_response(response, "Resolve").await)
+        }
+    }
+
+    /// Get an issue by the ref number shown in the Taiga UI (`#412`)
+    /// GET /api/v1/issues/by_ref?project={project_id}&ref={ref}
+    pub async fn get_issue_by_ref(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        ref_: i64,
+    ) -> Result<IssueDetailDto, TaigaClientError> {
+        let url = self.build_url("issues/by_ref")?;
+        log::info!("Fetching issue #{} of project {}", ref_, project_id);
+
+        let req = self
+            .client
+            .get(url)
+            .query(&[
+                ("project", project_id.to_string()),
+                ("ref", ref_.to_string()),
+            ])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Get issue by ref response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "issue detail").await
+        } else {
+            Err(Self::error_from_response(response, "Get issue by ref").await)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::SearchResultsDto;
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// Full-text search over the subjects and descriptions of a project's items
+    /// GET /api/v1/search?project={project_id}&text={text}
+    pub async fn search(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        text: &str,
+    ) -> Result<SearchResultsDto, TaigaClientError> {
+        let url = self.build_url("search")?;
+        log::info!("Searching project {} for '{}'", project_id, text);
+
+        let req = self
+            .client
+            .get(url)
+            .query(&[
+                ("project", project_id.to_string()),
+                ("text", text.to_string()),
+            ])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Search response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "search results").await
+        } else {
+            Err(Self::error_from_response(response, "Search").await)
+        }
+    }
+}
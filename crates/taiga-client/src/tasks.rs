@@ -0,0 +1,133 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{
+    CreateTaskRequest, IssueHistoryEntryDto, PatchTaskRequest, TaskDetailDto, TaskDto,
+};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List every task of a project matching the given filters, following every page
+    /// Pass `("user_story", id)` in the filters to list a story's tasks
+    /// GET /api/v1/tasks?project={project_id}
+    pub async fn list_tasks(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        filters: Option<Vec<(String, String)>>,
+    ) -> Result<Vec<TaskDto>, TaigaClientError> {
+        log::info!("Fetching tasks for project {}", project_id);
+
+        let mut query = vec![("project".to_string(), project_id.to_string())];
+        query.extend(filters.unwrap_or_default());
+
+        let tasks = self
+            .fetch_all_pages::<TaskDto>(token, "tasks", &query)
+            .await?;
+        log::info!("Found {} tasks in project {}", tasks.len(), project_id);
+        Ok(tasks)
+    }
+
+    /// Fetch detailed task information by ID
+    /// GET /api/v1/tasks/{task_id}
+    pub async fn get_task(
+        &self,
+        token: &Secret<String>,
+        task_id: i64,
+    ) -> Result<TaskDetailDto, TaigaClientError> {
+        let url = self.build_url(&format!("tasks/{}", task_id))?;
+        log::info!("Fetching task {} from {}", task_id, url);
+
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Get Task response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "task detail").await
+        } else {
+            Err(Self::error_from_response(response, "Get Task").await)
+        }
+    }
+
+    /// Create a new task, optionally under a user story
+    /// POST /api/v1/tasks
+    pub async fn create_task(
+        &self,
+        token: &Secret<String>,
+        request: CreateTaskRequest,
+    ) -> Result<TaskDetailDto, TaigaClientError> {
+        let url = self.build_url("tasks")?;
+        log::info!(
+            "Creating task in project {} (user story {:?}) at {}",
+            request.project,
+            request.user_story,
+            url
+        );
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Create Task response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "created task").await
+        } else {
+            Err(Self::error_from_response(response, "Create Task").await)
+        }
+    }
+
+    /// Patch a task (status, assignee, comment, ...)
+    /// PATCH /api/v1/tasks/{task_id}
+    /// Uses version field for optimistic locking - returns VersionConflict on mismatch
+    pub async fn patch_task(
+        &self,
+        token: &Secret<String>,
+        task_id: i64,
+        request: PatchTaskRequest,
+    ) -> Result<TaskDetailDto, TaigaClientError> {
+        let url = self.build_url(&format!("tasks/{}", task_id))?;
+        log::info!(
+            "Patching task {} at {} with version {}",
+            task_id,
+            url,
+            request.version
+        );
+
+        let req = self
+            .client
+            .patch(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Patch Task response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "patched task").await
+        } else {
+            Err(Self::patch_error_from_response(response, "Patch Task").await)
+        }
+    }
+
+    /// Fetch task history (comments and changes), following every page
+    /// GET /api/v1/history/task/{task_id}
+    pub async fn get_task_history(
+        &self,
+        token: &Secret<String>,
+        task_id: i64,
+    ) -> Result<Vec<IssueHistoryEntryDto>, TaigaClientError> {
+        log::info!("Fetching task history {}", task_id);
+
+        self.fetch_all_pages::<IssueHistoryEntryDto>(
+            token,
+            &format!("history/task/{}", task_id),
+            &[],
+        )
+        .await
+    }
+}
@@ -1,7 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::TaigaClient;
+    use crate::errors::{TaigaApiError, TaigaClientError};
+    use crate::pagination::PageInfo;
+    use crate::retry::RetryPolicy;
+    use crate::{body_excerpt, TaigaClient};
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+    use secrecy::Secret;
+    use std::time::Duration;
     use url::Url;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
     fn test_is_managed_url() {
@@ -23,4 +32,1479 @@ mod tests {
         // Invalid URL
         assert!(!client.is_managed_url("not-a-url"));
     }
+
+    fn test_token() -> Secret<String> {
+        Secret::new("test-token".to_string())
+    }
+
+    fn client_for(server: &MockServer) -> TaigaClient {
+        TaigaClient::new(Url::parse(&format!("{}/", server.uri())).unwrap())
+    }
+
+    fn issue_json(id: i64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "subject": format!("Issue {}", id),
+            "project": 1,
+            "status": 1,
+        })
+    }
+
+    #[test]
+    fn test_body_excerpt_cuts_on_char_boundary() {
+        let body = "é".repeat(1001);
+        assert_eq!(body_excerpt(&body, 2001).len(), 2000);
+        assert_eq!(body_excerpt("short", 2000), "short");
+    }
+
+    #[test]
+    fn test_page_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-paginated-count", HeaderValue::from_static("42"));
+        headers.insert(
+            "x-pagination-next",
+            HeaderValue::from_static("https://api.taiga.io/api/v1/issues?page=2"),
+        );
+        let info = PageInfo::from_headers(&headers);
+        assert_eq!(info.total_count, Some(42));
+        assert!(info.has_next);
+
+        // Last page: Taiga sends an empty next header
+        headers.insert("x-pagination-next", HeaderValue::from_static(""));
+        assert!(!PageInfo::from_headers(&headers).has_next);
+
+        // Unpaginated response
+        assert_eq!(
+            PageInfo::from_headers(&HeaderMap::new()),
+            PageInfo::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_follows_every_page() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues"))
+            .and(query_param("project", "1"))
+            .and(query_param("page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-paginated", "true")
+                    .insert_header("x-paginated-count", "3")
+                    .insert_header("x-pagination-next", "http://taiga/api/v1/issues?page=2")
+                    .set_body_json(vec![issue_json(1), issue_json(2)]),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues"))
+            .and(query_param("project", "1"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-paginated", "true")
+                    .insert_header("x-paginated-count", "3")
+                    .insert_header("x-pagination-next", "")
+                    .set_body_json(vec![issue_json(3)]),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issues = client_for(&server)
+            .list_issues(&test_token(), 1, None)
+            .await
+            .unwrap();
+
+        let ids: Vec<i64> = issues.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page_reports_total_count() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues"))
+            .and(query_param("page", "2"))
+            .and(query_param("status", "5"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-paginated-count", "61")
+                    .insert_header("x-pagination-next", "http://taiga/api/v1/issues?page=3")
+                    .set_body_json(vec![issue_json(31)]),
+            )
+            .mount(&server)
+            .await;
+
+        let query =
+            TaigaClient::issue_list_query(1, Some(vec![("status".to_string(), "5".to_string())]));
+        let page = client_for(&server)
+            .fetch_page::<crate::models::IssueDto>(&test_token(), "issues", &query, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(page.page, 2);
+        assert_eq!(page.total_count, Some(61));
+        assert!(page.has_next);
+        assert_eq!(page.items.len(), 1);
+    }
+
+    fn fast_retry_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        }
+    }
+
+    fn me_json() -> serde_json::Value {
+        serde_json::json!({
+            "id": 7,
+            "username": "jdoe",
+            "full_name": "John Doe",
+            "email": "jdoe@example.com",
+            "photo": null,
+            "big_photo": null,
+            "gravatar_id": "",
+            "is_active": true,
+        })
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(350),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_server_errors_then_succeeds() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(me_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let me = client_for(&server)
+            .with_retry_policy(fast_retry_policy(3))
+            .get_me(&test_token())
+            .await
+            .unwrap();
+
+        assert_eq!(me.id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_honors_retry_after_then_reports_rate_limited() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .with_retry_policy(fast_retry_policy(2))
+            .get_me(&test_token())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TaigaClientError::RateLimited {
+                retry_after: Some(d)
+            } if d == Duration::from_secs(0)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_report_server_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/projects/1"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .with_retry_policy(fast_retry_policy(1))
+            .get_project(&test_token(), 1)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::ServerError(s) if s.as_u16() == 503));
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_requests_are_not_retried() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/auth"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .with_retry_policy(fast_retry_policy(3))
+            .login("jdoe", "secret")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::ServerError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .with_retry_policy(fast_retry_policy(3))
+            .get_me(&test_token())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_and_not_found_keep_the_reason() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues/1"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "_error_message": "You do not have permission to perform this action.",
+                "_error_type": "taiga.base.exceptions.PermissionDenied"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues/2"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "_error_message": "No Issue matches the given query."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        match client.get_issue(&test_token(), 1).await.unwrap_err() {
+            TaigaClientError::Api(api) => {
+                assert_eq!(api.status, StatusCode::FORBIDDEN);
+                assert_eq!(
+                    api.message.as_deref(),
+                    Some("You do not have permission to perform this action.")
+                );
+            }
+            other => panic!("expected Api error, got {:?}", other),
+        }
+        match client.get_issue(&test_token(), 2).await.unwrap_err() {
+            TaigaClientError::Api(api) => assert_eq!(api.status, StatusCode::NOT_FOUND),
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_api_error_parses_message_and_field_errors() {
+        let body = r#"{
+            "_error_message": "Invalid data",
+            "_error_type": "taiga.base.exceptions.WrongArguments",
+            "status": ["Invalid pk \"99\" - object does not exist."],
+            "subject": "This field may not be blank."
+        }"#;
+        let err = TaigaApiError::from_body(StatusCode::BAD_REQUEST, body);
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.message.as_deref(), Some("Invalid data"));
+        assert_eq!(
+            err.field_errors.get("status"),
+            Some(&vec![
+                "Invalid pk \"99\" - object does not exist.".to_string()
+            ])
+        );
+        assert_eq!(
+            err.field_errors.get("subject"),
+            Some(&vec!["This field may not be blank.".to_string()])
+        );
+        assert!(!err.field_errors.contains_key("_error_type"));
+    }
+
+    #[test]
+    fn test_api_error_tolerates_non_json_bodies() {
+        let err = TaigaApiError::from_body(StatusCode::BAD_REQUEST, "<html>Bad Request</html>");
+        assert_eq!(err.message, None);
+        assert!(err.field_errors.is_empty());
+        assert_eq!(
+            err.to_string(),
+            "Taiga rejected the request (400 Bad Request)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_issue_validation_error_is_structured() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/issues/12"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "status": ["Invalid pk \"99\" - object does not exist."]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::PatchIssueRequest {
+            version: 3,
+            status: Some(99),
+            comment: None,
+            description: None,
+            subject: None,
+            assigned_to: None,
+            priority: None,
+            severity: None,
+            type_: None,
+            tags: None,
+        };
+
+        let err = client_for(&server)
+            .patch_issue(&test_token(), 12, request)
+            .await
+            .unwrap_err();
+
+        match err {
+            TaigaClientError::Api(api) => {
+                assert_eq!(api.status, StatusCode::BAD_REQUEST);
+                assert!(api.field_errors.contains_key("status"));
+            }
+            other => panic!("expected Api error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_omits_unset_fields() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issues"))
+            .and(body_json(serde_json::json!({
+                "project": 1,
+                "subject": "Crash on save",
+                "type": 3,
+                "tags": [["bug", null]],
+                "watchers": [7],
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(issue_json(55)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::CreateIssueRequest {
+            project: 1,
+            subject: "Crash on save".to_string(),
+            description: None,
+            status: None,
+            type_: Some(3),
+            priority: None,
+            severity: None,
+            assigned_to: None,
+            tags: vec![("bug".to_string(), None)],
+            watchers: vec![7],
+        };
+
+        let issue = client_for(&server)
+            .create_issue(&test_token(), request)
+            .await
+            .unwrap();
+
+        assert_eq!(issue.id, 55);
+    }
+
+    #[tokio::test]
+    async fn test_delete_issue() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/issues/12"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client_for(&server)
+            .delete_issue(&test_token(), 12)
+            .await
+            .unwrap();
+    }
+
+    fn user_story_detail_json(id: i64, version: i64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "ref": 40 + id,
+            "subject": format!("Story {}", id),
+            "description": null,
+            "description_html": null,
+            "project": 1,
+            "project_extra_info": { "id": 1, "name": "Larch", "slug": "larch", "logo_small_url": null },
+            "status": 2,
+            "status_extra_info": { "name": "In progress", "color": "#ff9900", "is_closed": false },
+            "owner": 7,
+            "owner_extra_info": null,
+            "assigned_to": null,
+            "assigned_to_extra_info": null,
+            "tags": [["backend", null]],
+            "total_points": 5.0,
+            "blocked_note": null,
+            "due_date": null,
+            "due_date_status": null,
+            "created_date": "2024-01-01T10:00:00Z",
+            "modified_date": "2024-01-02T10:00:00Z",
+            "finish_date": null,
+            "version": version,
+            "neighbors": null,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_user_stories_passes_filters() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/userstories"))
+            .and(query_param("project", "1"))
+            .and(query_param("status", "2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "id": 3,
+                    "ref": 43,
+                    "subject": "Story 3",
+                    "project": 1,
+                    "status": 2,
+                    "status_extra_info": null,
+                    "owner": 7,
+                    "assigned_to": null,
+                    "assigned_to_extra_info": null,
+                    "modified_date": "2024-01-02T10:00:00Z",
+                    "total_points": null,
+                }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let stories = client_for(&server)
+            .list_user_stories(
+                &test_token(),
+                1,
+                Some(vec![("status".to_string(), "2".to_string())]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(stories.len(), 1);
+        assert_eq!(stories[0].ref_, 43);
+        assert_eq!(stories[0].total_points, None);
+    }
+
+    #[tokio::test]
+    async fn test_patch_user_story_sends_only_changed_fields() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/userstories/3"))
+            .and(body_json(serde_json::json!({ "version": 4, "status": 5 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(user_story_detail_json(3, 5)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::PatchUserStoryRequest {
+            version: 4,
+            status: Some(5),
+            ..Default::default()
+        };
+
+        let story = client_for(&server)
+            .patch_user_story(&test_token(), 3, request)
+            .await
+            .unwrap();
+
+        assert_eq!(story.version, 5);
+        assert_eq!(story.total_points, Some(5.0));
+    }
+
+    #[tokio::test]
+    async fn test_patch_user_story_version_conflict() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/userstories/3"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "version": "The version doesn't match with the current one"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::PatchUserStoryRequest {
+            version: 1,
+            subject: Some("Renamed".to_string()),
+            ..Default::default()
+        };
+
+        let err = client_for(&server)
+            .patch_user_story(&test_token(), 3, request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::VersionConflict(_)));
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_for_user_story() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/tasks"))
+            .and(query_param("project", "1"))
+            .and(query_param("user_story", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": 9,
+                "ref": 51,
+                "subject": "Write migration",
+                "project": 1,
+                "user_story": 3,
+                "user_story_extra_info": { "id": 3, "ref": 43, "subject": "Story 3", "epics": null },
+                "status": 4,
+                "status_extra_info": { "name": "New", "color": "#999999", "is_closed": false },
+                "owner": 7,
+                "assigned_to": null,
+                "assigned_to_extra_info": null,
+                "modified_date": "2024-01-02T10:00:00Z",
+                "is_closed": false,
+            }])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tasks = client_for(&server)
+            .list_tasks(
+                &test_token(),
+                1,
+                Some(vec![("user_story".to_string(), "3".to_string())]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].user_story, Some(3));
+        assert_eq!(
+            tasks[0].user_story_extra_info.as_ref().map(|us| us.ref_),
+            Some(43)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_task_under_user_story() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/tasks"))
+            .and(body_json(serde_json::json!({
+                "project": 1,
+                "subject": "Write migration",
+                "user_story": 3,
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": 9,
+                "ref": 51,
+                "subject": "Write migration",
+                "description": "",
+                "description_html": "",
+                "project": 1,
+                "project_extra_info": { "id": 1, "name": "Larch", "slug": "larch", "logo_small_url": null },
+                "user_story": 3,
+                "user_story_extra_info": { "id": 3, "ref": 43, "subject": "Story 3" },
+                "status": 4,
+                "status_extra_info": null,
+                "owner": 7,
+                "owner_extra_info": null,
+                "assigned_to": null,
+                "assigned_to_extra_info": null,
+                "blocked_note": "",
+                "due_date": null,
+                "created_date": "2024-01-02T10:00:00Z",
+                "modified_date": "2024-01-02T10:00:00Z",
+                "finished_date": null,
+                "version": 1,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::CreateTaskRequest {
+            project: 1,
+            subject: "Write migration".to_string(),
+            user_story: Some(3),
+            description: None,
+            status: None,
+            assigned_to: None,
+        };
+
+        let task = client_for(&server)
+            .create_task(&test_token(), request)
+            .await
+            .unwrap();
+
+        assert_eq!(task.id, 9);
+        assert_eq!(task.user_story, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_patch_task_detaches_user_story() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/tasks/9"))
+            .and(body_json(serde_json::json!({ "version": 1, "user_story": null })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 9,
+                "ref": 51,
+                "subject": "Write migration",
+                "description": "",
+                "description_html": "",
+                "project": 1,
+                "project_extra_info": { "id": 1, "name": "Larch", "slug": "larch", "logo_small_url": null },
+                "user_story": null,
+                "user_story_extra_info": null,
+                "status": 4,
+                "status_extra_info": null,
+                "owner": 7,
+                "owner_extra_info": null,
+                "assigned_to": null,
+                "assigned_to_extra_info": null,
+                "blocked_note": "",
+                "due_date": null,
+                "created_date": "2024-01-02T10:00:00Z",
+                "modified_date": "2024-01-03T10:00:00Z",
+                "finished_date": null,
+                "version": 2,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::PatchTaskRequest {
+            version: 1,
+            user_story: Some(None),
+            ..Default::default()
+        };
+
+        let task = client_for(&server)
+            .patch_task(&test_token(), 9, request)
+            .await
+            .unwrap();
+
+        assert_eq!(task.user_story, None);
+        assert_eq!(task.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_link_and_unlink_epic_user_story() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/epics/5/related_userstories"))
+            .and(body_json(serde_json::json!({ "epic": 5, "user_story": 3 })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "epic": 5,
+                "user_story": 3,
+                "order": 100,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/epics/5/related_userstories/3"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let link = client
+            .link_epic_user_story(&test_token(), 5, 3)
+            .await
+            .unwrap();
+        assert_eq!(link.order, 100);
+
+        client
+            .unlink_epic_user_story(&test_token(), 5, 3)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_open_milestones_and_stats() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/milestones"))
+            .and(query_param("project", "1"))
+            .and(query_param("closed", "false"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "id": 11,
+                    "name": "Sprint 4",
+                    "slug": "sprint-4",
+                    "project": 1,
+                    "estimated_start": "2024-03-04",
+                    "estimated_finish": "2024-03-15",
+                    "closed": false,
+                    "total_points": 21.0,
+                    "closed_points": null,
+                    "created_date": "2024-03-01T09:00:00Z",
+                    "modified_date": "2024-03-01T09:00:00Z",
+                    "user_stories": [],
+                }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/milestones/11/stats"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "Sprint 4",
+                "estimated_start": "2024-03-04",
+                "estimated_finish": "2024-03-15",
+                "total_points": { "3": 13.0, "4": 8.0 },
+                "completed_points": [5.0],
+                "total_userstories": 6,
+                "completed_userstories": 2,
+                "total_tasks": 14,
+                "completed_tasks": 9,
+                "iocaine_doses": 0,
+                "days": [{ "day": "2024-03-04", "name": 4, "open_points": 21.0, "optimal_points": 21.0 }],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let milestones = client
+            .list_milestones(&test_token(), 1, Some(false))
+            .await
+            .unwrap();
+        assert_eq!(milestones.len(), 1);
+        assert_eq!(milestones[0].estimated_finish, "2024-03-15");
+
+        let stats = client
+            .get_milestone_stats(&test_token(), milestones[0].id)
+            .await
+            .unwrap();
+        assert_eq!(stats.completed_userstories, 2);
+        assert_eq!(stats.days.len(), 1);
+    }
+
+    fn wiki_page_json(version: i64, content: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": 8,
+            "project": 1,
+            "slug": "runbook",
+            "content": content,
+            "html": format!("<p>{}</p>", content),
+            "owner": 7,
+            "last_modifier": 7,
+            "created_date": "2024-01-01T10:00:00Z",
+            "modified_date": "2024-01-02T10:00:00Z",
+            "editions": version,
+            "version": version,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_wiki_page_by_slug() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/wiki/by_slug"))
+            .and(query_param("project", "1"))
+            .and(query_param("slug", "runbook"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(wiki_page_json(3, "Restart it")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let page = client_for(&server)
+            .get_wiki_page_by_slug(&test_token(), 1, "runbook")
+            .await
+            .unwrap();
+
+        assert_eq!(page.id, 8);
+        assert_eq!(page.html.as_deref(), Some("<p>Restart it</p>"));
+    }
+
+    #[tokio::test]
+    async fn test_patch_wiki_page_sends_version() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/wiki/8"))
+            .and(body_json(
+                serde_json::json!({ "version": 3, "content": "Reboot it" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(wiki_page_json(4, "Reboot it")))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/wiki/8"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "version": "The version doesn't match with the current one"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let request = crate::models::PatchWikiPageRequest {
+            version: 3,
+            content: "Reboot it".to_string(),
+        };
+
+        let page = client
+            .patch_wiki_page(&test_token(), 8, request.clone())
+            .await
+            .unwrap();
+        assert_eq!(page.version, 4);
+
+        // Re-sending the stale version is rejected
+        let err = client
+            .patch_wiki_page(&test_token(), 8, request)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TaigaClientError::VersionConflict(_)));
+    }
+
+    #[tokio::test]
+    async fn test_watch_and_vote_issue_post_without_body() {
+        let server = MockServer::start().await;
+
+        for action in ["watch", "unwatch", "upvote", "downvote"] {
+            Mock::given(method("POST"))
+                .and(path(format!("/api/v1/issues/5/{}", action)))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = client_for(&server);
+        let token = test_token();
+        client.watch_issue(&token, 5).await.unwrap();
+        client.unwatch_issue(&token, 5).await.unwrap();
+        client.upvote_issue(&token, 5).await.unwrap();
+        client.downvote_issue(&token, 5).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_issue_voters() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues/5/voters"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": 7, "username": "lead", "full_name": "Team Lead" },
+                { "id": 9, "username": "dev" }
+            ])))
+            .mount(&server)
+            .await;
+
+        let voters = client_for(&server)
+            .list_issue_voters(&test_token(), 5)
+            .await
+            .unwrap();
+
+        assert_eq!(voters.len(), 2);
+        assert_eq!(voters[0].full_name.as_deref(), Some("Team Lead"));
+        assert_eq!(voters[1].full_name, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_custom_attributes() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issue-custom-attributes"))
+            .and(query_param("project", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "id": 3,
+                    "name": "Customer",
+                    "description": "",
+                    "type": "dropdown",
+                    "order": 1,
+                    "project": 1,
+                    "extra": ["ACME", "Globex"]
+                },
+                {
+                    "id": 4,
+                    "name": "Found in version",
+                    "type": "text",
+                    "order": 2,
+                    "project": 1,
+                    "extra": null
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let attributes = client_for(&server)
+            .get_issue_custom_attributes(&test_token(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[0].type_, "dropdown");
+        assert!(attributes[1].extra.is_null());
+    }
+
+    #[tokio::test]
+    async fn test_patch_issue_custom_attributes_values_version_conflict() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/issues/custom-attributes-values/5"))
+            .and(body_json(serde_json::json!({
+                "version": 2,
+                "attributes_values": { "3": "ACME" }
+            })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "version": "The version doesn't match with the current one"
+            })))
+            .mount(&server)
+            .await;
+
+        let mut values = serde_json::Map::new();
+        values.insert("3".to_string(), serde_json::json!("ACME"));
+        let request = crate::models::PatchIssueCustomAttributesValuesRequest {
+            version: 2,
+            attributes_values: values,
+        };
+
+        let err = client_for(&server)
+            .patch_issue_custom_attributes_values(&test_token(), 5, request)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TaigaClientError::VersionConflict(_)));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_create_issues_sends_subjects_per_line() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issues/bulk_create"))
+            .and(body_json(serde_json::json!({
+                "project_id": 1,
+                "bulk_issues": "First\nSecond"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([issue_json(11), issue_json(12)])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = crate::models::BulkCreateIssuesRequest {
+            project_id: 1,
+            bulk_issues: "First\nSecond".to_string(),
+        };
+        let issues = client_for(&server)
+            .bulk_create_issues(&test_token(), request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            issues.iter().map(|i| i.id).collect::<Vec<_>>(),
+            vec![11, 12]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_parses_issues_and_user_stories() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/search"))
+            .and(query_param("project", "1"))
+            .and(query_param("text", "login crash"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "count": 2,
+                "issues": [
+                    { "id": 5, "ref": 12, "subject": "Login crash", "status": 1, "assigned_to": null }
+                ],
+                "userstories": [
+                    { "id": 9, "ref": 3, "subject": "Login page", "status": 2, "total_points": 3.0 }
+                ],
+                "tasks": [],
+                "wikipages": [],
+                "epics": []
+            })))
+            .mount(&server)
+            .await;
+
+        let results = client_for(&server)
+            .search(&test_token(), 1, "login crash")
+            .await
+            .unwrap();
+
+        assert_eq!(results.count, 2);
+        assert_eq!(results.issues[0].ref_, 12);
+        assert_eq!(results.userstories[0].assigned_to, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_filters_data_passes_filters() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues/filters_data"))
+            .and(query_param("project", "1"))
+            .and(query_param("status", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "statuses": [
+                    { "id": 3, "name": "New", "color": "#70728F", "order": 1, "count": 4 }
+                ],
+                "assigned_to": [
+                    { "id": null, "full_name": "", "count": 1 },
+                    { "id": 7, "full_name": "Ada Lovelace", "count": 3 }
+                ],
+                "tags": [{ "name": "backend", "color": null, "count": 2 }],
+                "roles": []
+            })))
+            .mount(&server)
+            .await;
+
+        let facets = client_for(&server)
+            .get_issue_filters_data(
+                &test_token(),
+                1,
+                Some(vec![("status".to_string(), "3".to_string())]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(facets.statuses[0].count, 4);
+        assert_eq!(facets.assigned_to[0].id, None);
+        assert_eq!(facets.tags[0].name, "backend");
+        assert!(facets.priorities.is_empty());
+    }
+
+    fn temp_destination(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("taiga-client-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("attachment.log")
+    }
+
+    #[tokio::test]
+    async fn test_download_to_path_streams_with_progress() {
+        let server = MockServer::start().await;
+        let body = vec![b'x'; 64 * 1024];
+
+        Mock::given(method("GET"))
+            .and(path("/media/attachment.log"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer test-token",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+            .mount(&server)
+            .await;
+
+        let destination = temp_destination("download");
+        let cancel = std::sync::atomic::AtomicBool::new(false);
+        let mut updates = Vec::new();
+
+        let written = client_for(&server)
+            .download_to_path(
+                &format!("{}/media/attachment.log", server.uri()),
+                Some(&test_token()),
+                &destination,
+                &cancel,
+                |p| updates.push(p),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(written, body.len() as u64);
+        assert_eq!(std::fs::read(&destination).unwrap(), body);
+        assert_eq!(updates.first().unwrap().transferred, 0);
+        assert_eq!(updates.last().unwrap().transferred, body.len() as u64);
+        assert_eq!(updates.last().unwrap().total, Some(body.len() as u64));
+
+        std::fs::remove_dir_all(destination.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_to_path_skips_token_for_foreign_host_and_cancels() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/file.bin"))
+            .and(|req: &wiremock::Request| !req.headers.contains_key("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1u8; 1024]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The API lives on another host, so the mock server is a foreign host
+        let client = TaigaClient::new(Url::parse("https://api.taiga.io/").unwrap());
+        let destination = temp_destination("cancel");
+        let cancel = std::sync::atomic::AtomicBool::new(true);
+
+        let err = client
+            .download_to_path(
+                &format!("{}/file.bin", server.uri()),
+                Some(&test_token()),
+                &destination,
+                &cancel,
+                |_| {},
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::Cancelled));
+        assert!(!destination.exists());
+        assert!(!destination.with_extension("log.part").exists());
+
+        std::fs::remove_dir_all(destination.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_from_path_streams_file() {
+        let server = MockServer::start().await;
+        let source = temp_destination("upload");
+        let contents = vec![b'y'; 150 * 1024];
+        std::fs::write(&source, &contents).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issues/attachments"))
+            .and(|req: &wiremock::Request| {
+                let body = String::from_utf8_lossy(&req.body);
+                body.contains("name=\"object_id\"\r\n\r\n42")
+                    && body.contains("filename=\"attachment.log\"")
+                    && req.body.windows(1024).any(|w| w.iter().all(|b| *b == b'y'))
+            })
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": 9,
+                "name": "attachment.log",
+                "url": "https://media.example.com/attachment.log",
+                "attached_file": "attachments/attachment.log",
+                "thumbnail_card_url": null,
+                "preview_url": null,
+                "size": 153600,
+                "created_date": "2024-03-01T10:00:00Z",
+                "is_deprecated": false,
+                "description": "",
+                "order": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let updates = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = std::sync::Arc::clone(&updates);
+        let upload = crate::transfer::AttachmentUpload {
+            project_id: 1,
+            issue_id: 42,
+            path: source.clone(),
+            mime_type: Some("text/plain".to_string()),
+            max_size: Some(1024 * 1024),
+        };
+
+        let attachment = client_for(&server)
+            .upload_attachment_from_path(
+                &test_token(),
+                &upload,
+                std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                move |p| recorded.lock().unwrap().push(p),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(attachment.id, 9);
+        let updates = updates.lock().unwrap();
+        assert_eq!(updates.first().unwrap().transferred, 0);
+        assert_eq!(updates.last().unwrap().transferred, contents.len() as u64);
+        assert_eq!(updates.last().unwrap().total, Some(contents.len() as u64));
+
+        std::fs::remove_dir_all(source.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_from_path_rejects_file_over_limit() {
+        let server = MockServer::start().await;
+        let source = temp_destination("upload-limit");
+        std::fs::write(&source, vec![0u8; 2048]).unwrap();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let upload = crate::transfer::AttachmentUpload {
+            project_id: 1,
+            issue_id: 42,
+            path: source.clone(),
+            mime_type: None,
+            max_size: Some(1024),
+        };
+
+        let err = client_for(&server)
+            .upload_attachment_from_path(
+                &test_token(),
+                &upload,
+                std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                |_| {},
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TaigaClientError::FileTooLarge {
+                size: 2048,
+                limit: 1024
+            }
+        ));
+
+        std::fs::remove_dir_all(source.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resolve_slug_and_ref_then_get_issue_by_ref() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/resolver"))
+            .and(query_param("project", "acme"))
+            .and(query_param("ref", "412"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "project": 3, "issue": 1201 })),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issues/by_ref"))
+            .and(query_param("project", "3"))
+            .and(query_param("ref", "412"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 1201,
+                "ref": 412,
+                "subject": "Login button misaligned",
+                "description": null,
+                "description_html": null,
+                "project": 3,
+                "project_extra_info": { "id": 3, "name": "Acme", "slug": "acme" },
+                "status": 1,
+                "status_extra_info": null,
+                "type": null,
+                "priority": null,
+                "severity": null,
+                "owner": null,
+                "owner_extra_info": null,
+                "assigned_to": null,
+                "assigned_to_extra_info": null,
+                "blocked_note": null,
+                "due_date": null,
+                "due_date_status": null,
+                "created_date": "2024-03-01T10:00:00Z",
+                "modified_date": "2024-03-02T10:00:00Z",
+                "finished_date": null,
+                "version": 4,
+                "neighbors": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let resolved = client
+            .resolve(&test_token(), "acme", Some(412))
+            .await
+            .unwrap();
+        assert_eq!(resolved.project, 3);
+        assert_eq!(resolved.issue, Some(1201));
+        assert_eq!(resolved.us, None);
+
+        let issue = client
+            .get_issue_by_ref(&test_token(), resolved.project, 412)
+            .await
+            .unwrap();
+        assert_eq!(issue.id, 1201);
+        assert_eq!(issue.ref_, 412);
+    }
+
+    #[tokio::test]
+    async fn test_get_project_issues_stats() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/projects/3/issues_stats"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_issues": 5,
+                "opened_issues": 4,
+                "closed_issues": 1,
+                "issues_per_priority": {
+                    "7": { "id": 7, "name": "High", "color": "#f00", "count": 3 }
+                },
+                "issues_per_assigned_to": {
+                    "0": { "id": 0, "name": "Unassigned", "color": "black", "count": 2 },
+                    "12": { "id": 12, "name": "Ada Lovelace", "color": "black", "count": 3 }
+                },
+                "issues_per_owner": {},
+                "last_four_weeks_days": {
+                    "by_open_closed": { "open": [3, 4], "closed": [0, 1] },
+                    "by_severity": {}
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let stats = client_for(&server)
+            .get_project_issues_stats(&test_token(), 3)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.opened_issues, 4);
+        assert_eq!(stats.issues_per_priority["7"].count, 3);
+        assert_eq!(stats.issues_per_assigned_to["0"].id, Some(0));
+        assert!(stats.issues_per_status.is_empty());
+        assert_eq!(stats.last_four_weeks_days.by_open_closed.closed, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_promote_issue_to_user_story_returns_refs() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issues/42/promote_to_user_story"))
+            .and(body_json(serde_json::json!({ "project_id": 3 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([57])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let refs = client_for(&server)
+            .promote_issue_to_user_story(&test_token(), 42, 3)
+            .await
+            .unwrap();
+
+        assert_eq!(refs, vec![57]);
+    }
+
+    #[tokio::test]
+    async fn test_builder_sends_user_agent_and_accept_language() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .and(wiremock::matchers::header("user-agent", "Larch/1.2"))
+            .and(wiremock::matchers::header("accept-language", "de"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(me_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TaigaClient::builder(Url::parse(&format!("{}/", server.uri())).unwrap())
+            .user_agent("Larch/1.2")
+            .accept_language("de")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_me(&test_token()).await.unwrap().id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_builder_read_timeout_stops_hung_server() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/users/me"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(me_json())
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = TaigaClient::builder(Url::parse(&format!("{}/", server.uri())).unwrap())
+            .read_timeout(Duration::from_millis(200))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let err = client.get_me(&test_token()).await.unwrap_err();
+
+        assert!(matches!(err, TaigaClientError::Reqwest(ref e) if e.is_timeout()));
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_proxy_and_certificates() {
+        let url = Url::parse("https://api.taiga.io/").unwrap();
+
+        let err = TaigaClient::builder(url.clone())
+            .proxy("not a proxy url")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, TaigaClientError::InvalidConfig(_)));
+
+        let err = TaigaClient::builder(url)
+            .add_root_certificates_pem("no certificate here")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, TaigaClientError::InvalidConfig(_)));
+    }
 }
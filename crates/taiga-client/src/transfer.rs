@@ -0,0 +1,217 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use reqwest::multipart;
+use secrecy::{ExposeSecret, Secret};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::errors::TaigaClientError;
+use crate::models::AttachmentDto;
+use crate::TaigaClient;
+
+/// Size of the chunks read from disk while uploading
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Bytes moved so far in a download or upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferProgress {
+    pub transferred: u64,
+    /// Unknown when the server sends no Content-Length
+    pub total: Option<u64>,
+}
+
+/// A local file to attach to an issue
+#[derive(Debug, Clone)]
+pub struct AttachmentUpload {
+    pub project_id: i64,
+    pub issue_id: i64,
+    pub path: PathBuf,
+    /// Defaults to `application/octet-stream`
+    pub mime_type: Option<String>,
+    /// Files larger than this are rejected before anything is sent
+    pub max_size: Option<u64>,
+}
+
+impl TaigaClient {
+    /// Stream a file (e.g. an attachment URL) to `destination` without buffering it in memory
+    /// The token is only sent to hosts that `is_managed_url` accepts. Data is written
+    /// to `<destination>.part` and renamed once complete, so a cancelled or failed
+    /// download never leaves a truncated file behind. Returns the number of bytes written.
+    pub async fn download_to_path<F>(
+        &self,
+        url: &str,
+        token: Option<&Secret<String>>,
+        destination: &Path,
+        cancel: &AtomicBool,
+        mut on_progress: F,
+    ) -> Result<u64, TaigaClientError>
+    where
+        F: FnMut(TransferProgress),
+    {
+        let mut req = self.client.get(url);
+        if let Some(t) = token {
+            if self.is_managed_url(url) {
+                req = req.bearer_auth(t.expose_secret());
+            }
+        }
+
+        let mut response = self.send(req).await?;
+        log::info!("Download response status: {}", response.status());
+
+        if !response.status().is_success() {
+            return Err(Self::error_from_response(response, "Download").await);
+        }
+
+        let total = response.content_length();
+        let partial = partial_path(destination);
+        let mut file = tokio::fs::File::create(&partial).await?;
+        let mut transferred = 0u64;
+        on_progress(TransferProgress { transferred, total });
+
+        let result = async {
+            loop {
+                if cancel.load(Ordering::Relaxed) {
+                    return Err(TaigaClientError::Cancelled);
+                }
+                let Some(chunk) = response.chunk().await? else {
+                    break;
+                };
+                file.write_all(&chunk).await?;
+                transferred += chunk.len() as u64;
+                on_progress(TransferProgress { transferred, total });
+            }
+            file.flush().await?;
+            Ok(())
+        }
+        .await;
+        drop(file);
+
+        if let Err(e) = result {
+            if let Err(remove_err) = tokio::fs::remove_file(&partial).await {
+                log::warn!(
+                    "Failed to remove partial download {}: {}",
+                    partial.display(),
+                    remove_err
+                );
+            }
+            return Err(e);
+        }
+
+        tokio::fs::rename(&partial, destination).await?;
+        log::info!(
+            "Downloaded {} bytes to {}",
+            transferred,
+            destination.display()
+        );
+        Ok(transferred)
+    }
+}
+
+impl TaigaClient {
+    /// Upload a local file as an issue attachment, reading it from disk in chunks
+    /// `on_progress` is called as chunks are handed to the connection. Setting
+    /// `cancel` aborts the request mid-stream with `TaigaClientError::Cancelled`.
+    /// POST /issues/attachments
+    pub async fn upload_attachment_from_path<F>(
+        &self,
+        token: &Secret<String>,
+        upload: &AttachmentUpload,
+        cancel: Arc<AtomicBool>,
+        mut on_progress: F,
+    ) -> Result<AttachmentDto, TaigaClientError>
+    where
+        F: FnMut(TransferProgress) + Send + 'static,
+    {
+        let url = self.build_url("issues/attachments")?;
+        let file = tokio::fs::File::open(&upload.path).await?;
+        let size = file.metadata().await?.len();
+        if let Some(limit) = upload.max_size {
+            if size > limit {
+                return Err(TaigaClientError::FileTooLarge { size, limit });
+            }
+        }
+
+        let file_name = upload
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "attachment".to_string());
+        let mime = upload
+            .mime_type
+            .clone()
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        log::info!(
+            "Uploading {} ({} bytes, {}) to issue {} in project {}",
+            upload.path.display(),
+            size,
+            mime,
+            upload.issue_id,
+            upload.project_id
+        );
+
+        on_progress(TransferProgress {
+            transferred: 0,
+            total: Some(size),
+        });
+        let stream_cancel = Arc::clone(&cancel);
+        let chunks = futures_util::stream::try_unfold(
+            (file, 0u64, on_progress),
+            move |(mut file, sent, mut on_progress)| {
+                let cancel = Arc::clone(&stream_cancel);
+                async move {
+                    if cancel.load(Ordering::Relaxed) {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::Interrupted,
+                            "upload cancelled",
+                        ));
+                    }
+                    let mut chunk = vec![0u8; UPLOAD_CHUNK_SIZE];
+                    let read = file.read(&mut chunk).await?;
+                    if read == 0 {
+                        return Ok(None);
+                    }
+                    chunk.truncate(read);
+                    let sent = sent + read as u64;
+                    on_progress(TransferProgress {
+                        transferred: sent,
+                        total: Some(size),
+                    });
+                    Ok(Some((chunk, (file, sent, on_progress))))
+                }
+            },
+        );
+
+        let file_part =
+            multipart::Part::stream_with_length(reqwest::Body::wrap_stream(chunks), size)
+                .file_name(file_name)
+                .mime_str(&mime)
+                .map_err(|_| TaigaClientError::InvalidMimeType(mime.clone()))?;
+        let form = multipart::Form::new()
+            .text("object_id", upload.issue_id.to_string())
+            .text("project", upload.project_id.to_string())
+            .part("attached_file", file_part);
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .multipart(form);
+        let response = match self.send(req).await {
+            Err(_) if cancel.load(Ordering::Relaxed) => return Err(TaigaClientError::Cancelled),
+            result => result?,
+        };
+        log::info!("Upload response status: {}", response.status());
+
+        if !response.status().is_success() {
+            return Err(Self::error_from_response(response, "Upload attachment").await);
+        }
+        Self::parse_json(response, "uploaded attachment").await
+    }
+}
+
+fn partial_path(destination: &Path) -> PathBuf {
+    let mut name = destination.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
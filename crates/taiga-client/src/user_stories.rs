@@ -0,0 +1,138 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{
+    IssueHistoryEntryDto, PatchUserStoryRequest, UserStoryDetailDto, UserStoryDto,
+};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List every user story of a project matching the given filters, following every page
+    /// GET /api/v1/userstories?project={project_id}
+    pub async fn list_user_stories(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        filters: Option<Vec<(String, String)>>,
+    ) -> Result<Vec<UserStoryDto>, TaigaClientError> {
+        log::info!("Fetching user stories for project {}", project_id);
+
+        let mut query = vec![("project".to_string(), project_id.to_string())];
+        query.extend(filters.unwrap_or_default());
+
+        let stories = self
+            .fetch_all_pages::<UserStoryDto>(token, "userstories", &query)
+            .await?;
+        log::info!(
+            "Found {} user stories in project {}",
+            stories.len(),
+            project_id
+        );
+        Ok(stories)
+    }
+
+    /// Fetch detailed user story information by ID
+    /// GET /api/v1/userstories/{story_id}
+    pub async fn get_user_story(
+        &self,
+        token: &Secret<String>,
+        story_id: i64,
+    ) -> Result<UserStoryDetailDto, TaigaClientError> {
+        let url = self.build_url(&format!("userstories/{}", story_id))?;
+        log::info!("Fetching user story {} from {}", story_id, url);
+
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Get User Story response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "user story detail").await
+        } else {
+            Err(Self::error_from_response(response, "Get User Story").await)
+        }
+    }
+
+    /// Fetch a user story by the ref number shown in the Taiga UI
+    /// GET /api/v1/userstories/by_ref?project={project_id}&ref={ref}
+    pub async fn get_user_story_by_ref(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        ref_: i64,
+    ) -> Result<UserStoryDetailDto, TaigaClientError> {
+        let url = self.build_url("userstories/by_ref")?;
+        log::info!("Fetching user story #{} of project {}", ref_, project_id);
+
+        let req = self
+            .client
+            .get(url)
+            .query(&[
+                ("project", project_id.to_string()),
+                ("ref", ref_.to_string()),
+            ])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!(
+            "Get User Story by ref response status: {}",
+            response.status()
+        );
+
+        if response.status().is_success() {
+            Self::parse_json(response, "user story detail").await
+        } else {
+            Err(Self::error_from_response(response, "Get User Story by ref").await)
+        }
+    }
+
+    /// Patch a user story (status, subject, description, comment, ...)
+    /// PATCH /api/v1/userstories/{story_id}
+    /// Uses version field for optimistic locking - returns VersionConflict on mismatch
+    pub async fn patch_user_story(
+        &self,
+        token: &Secret<String>,
+        story_id: i64,
+        request: PatchUserStoryRequest,
+    ) -> Result<UserStoryDetailDto, TaigaClientError> {
+        let url = self.build_url(&format!("userstories/{}", story_id))?;
+        log::info!(
+            "Patching user story {} at {} with version {}",
+            story_id,
+            url,
+            request.version
+        );
+
+        let req = self
+            .client
+            .patch(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Patch User Story response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "patched user story").await
+        } else {
+            Err(Self::patch_error_from_response(response, "Patch User Story").await)
+        }
+    }
+
+    /// Fetch user story history (comments and changes), following every page
+    /// GET /api/v1/history/userstory/{story_id}
+    pub async fn get_user_story_history(
+        &self,
+        token: &Secret<String>,
+        story_id: i64,
+    ) -> Result<Vec<IssueHistoryEntryDto>, TaigaClientError> {
+        log::info!("Fetching user story history {}", story_id);
+
+        self.fetch_all_pages::<IssueHistoryEntryDto>(
+            token,
+            &format!("history/userstory/{}", story_id),
+            &[],
+        )
+        .await
+    }
+}
@@ -0,0 +1,159 @@
+use secrecy::{ExposeSecret, Secret};
+
+use crate::errors::TaigaClientError;
+use crate::models::{CreateWikiPageRequest, PatchWikiPageRequest, WikiLinkDto, WikiPageDto};
+use crate::TaigaClient;
+
+impl TaigaClient {
+    /// List every wiki page of a project, following every page
+    /// GET /api/v1/wiki?project={project_id}
+    pub async fn list_wiki_pages(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<WikiPageDto>, TaigaClientError> {
+        log::info!("Fetching wiki pages for project {}", project_id);
+
+        let query = [("project".to_string(), project_id.to_string())];
+        self.fetch_all_pages::<WikiPageDto>(token, "wiki", &query)
+            .await
+    }
+
+    /// List the wiki sidebar links of a project
+    /// GET /api/v1/wiki-links?project={project_id}
+    pub async fn list_wiki_links(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<WikiLinkDto>, TaigaClientError> {
+        log::info!("Fetching wiki links for project {}", project_id);
+
+        let query = [("project".to_string(), project_id.to_string())];
+        self.fetch_all_pages::<WikiLinkDto>(token, "wiki-links", &query)
+            .await
+    }
+
+    /// Fetch a wiki page by ID
+    /// GET /api/v1/wiki/{page_id}
+    pub async fn get_wiki_page(
+        &self,
+        token: &Secret<String>,
+        page_id: i64,
+    ) -> Result<WikiPageDto, TaigaClientError> {
+        let url = self.build_url(&format!("wiki/{}", page_id))?;
+        log::info!("Fetching wiki page {} from {}", page_id, url);
+
+        let req = self.client.get(url).bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!("Get Wiki Page response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "wiki page").await
+        } else {
+            Err(Self::error_from_response(response, "Get Wiki Page").await)
+        }
+    }
+
+    /// Fetch a wiki page by its slug, as used in wiki links
+    /// GET /api/v1/wiki/by_slug?project={project_id}&slug={slug}
+    pub async fn get_wiki_page_by_slug(
+        &self,
+        token: &Secret<String>,
+        project_id: i64,
+        slug: &str,
+    ) -> Result<WikiPageDto, TaigaClientError> {
+        let url = self.build_url("wiki/by_slug")?;
+        log::info!(
+            "Fetching wiki page '{}' of project {} from {}",
+            slug,
+            project_id,
+            url
+        );
+
+        let req = self
+            .client
+            .get(url)
+            .query(&[
+                ("project", project_id.to_string()),
+                ("slug", slug.to_string()),
+            ])
+            .bearer_auth(token.expose_secret());
+        let response = self.send(req).await?;
+
+        log::info!(
+            "Get Wiki Page by slug response status: {}",
+            response.status()
+        );
+
+        if response.status().is_success() {
+            Self::parse_json(response, "wiki page").await
+        } else {
+            Err(Self::error_from_response(response, "Get Wiki Page by slug").await)
+        }
+    }
+
+    /// Create a new wiki page
+    /// POST /api/v1/wiki
+    pub async fn create_wiki_page(
+        &self,
+        token: &Secret<String>,
+        request: CreateWikiPageRequest,
+    ) -> Result<WikiPageDto, TaigaClientError> {
+        let url = self.build_url("wiki")?;
+        log::info!(
+            "Creating wiki page '{}' in project {} at {}",
+            request.slug,
+            request.project,
+            url
+        );
+
+        let req = self
+            .client
+            .post(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Create Wiki Page response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "created wiki page").await
+        } else {
+            Err(Self::error_from_response(response, "Create Wiki Page").await)
+        }
+    }
+
+    /// Edit a wiki page's content
+    /// PATCH /api/v1/wiki/{page_id}
+    /// Uses version field for optimistic locking - returns VersionConflict on mismatch
+    pub async fn patch_wiki_page(
+        &self,
+        token: &Secret<String>,
+        page_id: i64,
+        request: PatchWikiPageRequest,
+    ) -> Result<WikiPageDto, TaigaClientError> {
+        let url = self.build_url(&format!("wiki/{}", page_id))?;
+        log::info!(
+            "Patching wiki page {} at {} with version {}",
+            page_id,
+            url,
+            request.version
+        );
+
+        let req = self
+            .client
+            .patch(url)
+            .bearer_auth(token.expose_secret())
+            .json(&request);
+        let response = self.send(req).await?;
+
+        log::info!("Patch Wiki Page response status: {}", response.status());
+
+        if response.status().is_success() {
+            Self::parse_json(response, "patched wiki page").await
+        } else {
+            Err(Self::patch_error_from_response(response, "Patch Wiki Page").await)
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::error::Result;
+use crate::repositories::SqliteRepository;
+use crate::services::accounts::{AccountClients, AccountStore, AccountSummary};
+use crate::services::credential_store::CredentialVault;
+use crate::services::credentials;
+
+/// Accounts for the account switcher, in the order they were added
+#[tauri::command]
+pub async fn list_accounts(
+    repo: tauri::State<'_, SqliteRepository>,
+    accounts: tauri::State<'_, AccountClients>,
+) -> Result<Vec<AccountSummary>> {
+    let store = AccountStore::load(repo.inner()).await?;
+    Ok(store
+        .accounts
+        .iter()
+        .map(|account| AccountSummary {
+            id: account.id.clone(),
+            name: account.name.clone(),
+            api_url: account.api_url.clone(),
+            username: account.username.clone(),
+            active: store.active.as_deref() == Some(account.id.as_str()),
+            signed_in: accounts.is_signed_in(&account.id),
+        })
+        .collect())
+}
+
+/// Make another account the one commands act on
+/// A signed-out account can be activated; commands then fail with `Unauthorized`
+/// until the user signs in again.
+#[tauri::command]
+pub async fn switch_account(
+    repo: tauri::State<'_, SqliteRepository>,
+    accounts: tauri::State<'_, AccountClients>,
+    account_id: String,
+) -> Result<()> {
+    let mut store = AccountStore::load(repo.inner()).await?;
+    store.get(&account_id)?;
+    store.active = Some(account_id.clone());
+    store.save(repo.inner()).await?;
+
+    accounts.set_active(Some(account_id.clone()));
+    log::info!("Switched to account '{}'", account_id);
+    Ok(())
+}
+
+/// Sign out of an account and forget it, including its project selection
+#[tauri::command]
+pub async fn remove_account(
+    repo: tauri::State<'_, SqliteRepository>,
+    accounts: tauri::State<'_, AccountClients>,
+    vault: tauri::State<'_, CredentialVault>,
+    account_id: String,
+) -> Result<()> {
+    let mut store = AccountStore::load(repo.inner()).await?;
+    let removed = store.remove(&account_id)?;
+
+    let vault = vault.inner();
+    if let Ok(key) = removed.credential_key() {
+        credentials::delete_api_token(vault, &key)?;
+        credentials::delete_refresh_token(vault, &key)?;
+    }
+    credentials::delete_legacy_entries(vault, &account_id)?;
+    store.save(repo.inner()).await?;
+
+    accounts.remove(&account_id);
+    accounts.set_active(store.active);
+    log::info!("Removed account '{}'", account_id);
+    Ok(())
+}
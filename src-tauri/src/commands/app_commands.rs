@@ -1,4 +1,7 @@
 use crate::error::Result;
+use crate::repositories::SqliteRepository;
+use crate::services::accounts::AccountStore;
+use crate::services::connection_settings::ConnectionSettings;
 use tauri::AppHandle;
 
 /// Force close the application
@@ -9,3 +12,28 @@ pub fn force_close_app(app: AppHandle) -> Result<()> {
     app.exit(0);
     Ok(())
 }
+
+#[tauri::command]
+pub async fn get_connection_settings(
+    repository: tauri::State<'_, SqliteRepository>,
+) -> Result<ConnectionSettings> {
+    ConnectionSettings::load(repository.inner()).await
+}
+
+/// Validate and store the HTTP settings (timeouts, proxy, certificates, headers)
+/// They are used for the next login and when Larch restores the session on startup.
+#[tauri::command]
+pub async fn save_connection_settings(
+    repository: tauri::State<'_, SqliteRepository>,
+    settings: ConnectionSettings,
+) -> Result<()> {
+    let store = AccountStore::load(repository.inner()).await?;
+    let api_url = store
+        .active_account()
+        .map(|account| account.api_url.clone())
+        .unwrap_or_else(|_| "https://api.taiga.io/".to_string());
+    settings.build_client(api_url.parse()?)?;
+
+    log::info!("Saving connection settings");
+    settings.save(repository.inner()).await
+}
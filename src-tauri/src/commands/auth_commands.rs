@@ -1,102 +1,110 @@
 use crate::domain::user::User;
 use crate::error::Result;
-use crate::repositories::Repository;
-use crate::services::credentials;
-use secrecy::ExposeSecret;
-use taiga_client::TaigaClient;
-use tauri::Manager;
-
+use crate::services::accounts::{AccountClients, AccountStore};
+use crate::services::auth_client::AuthClient;
+use crate::services::connection_settings::ConnectionSettings;
+use crate::services::credential_store::CredentialVault;
+use crate::services::credentials::{self, CredentialKey};
+use crate::services::issue_links;
+
+/// Sign in to a Taiga instance and make that account the active one
+/// Signing in again as the same user on the same server reuses its account;
+/// `account_name` defaults to the server's host name.
 #[tauri::command]
 pub async fn login(
-    app_handle: tauri::AppHandle,
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    accounts: tauri::State<'_, AccountClients>,
+    vault: tauri::State<'_, CredentialVault>,
     api_url: &str,
     username: &str,
     password: &str,
+    account_name: Option<String>,
 ) -> Result<User> {
-    let client = TaigaClient::new(api_url.parse()?);
+    let settings = ConnectionSettings::load(repo.inner()).await?;
+    let client = settings.build_client(api_url.parse()?)?;
     let auth_detail = client.login(username, password).await?;
 
-    repo.save_config("taiga_api_url", api_url).await?;
+    // Key by the canonical username; people may sign in with their email address
+    let key = CredentialKey::new(api_url, &auth_detail.username)?;
+    let mut store = AccountStore::load(repo.inner()).await?;
+    let account_id = store.sign_in(account_name.as_deref(), api_url, &auth_detail.username);
 
-    credentials::set_api_token(&auth_detail.auth_token)?;
+    let vault = vault.inner();
+    credentials::set_api_token(vault, &key, &auth_detail.auth_token)?;
 
     if let Some(refresh) = &auth_detail.refresh {
-        credentials::set_refresh_token(refresh)?;
+        credentials::set_refresh_token(vault, &key, refresh)?;
     }
+    // A legacy account claimed by this login must not keep its old entries
+    credentials::delete_legacy_entries(vault, &account_id)?;
 
-    let token = credentials::get_api_token()?;
+    let token = credentials::get_api_token(vault, &key)?;
     let me = client.get_me(&token).await?;
 
-    app_handle.manage(client);
+    store.save(repo.inner()).await?;
+    accounts.insert(&account_id, AuthClient::new(client, vault.clone(), key));
+    accounts.set_active(Some(account_id.clone()));
+    log::info!("Signed in to account '{}'", account_id);
 
     Ok(me.into())
 }
 
 #[tauri::command]
-pub fn has_api_token() -> Result<bool> {
-    Ok(credentials::get_api_token().is_ok())
+pub fn has_api_token(accounts: tauri::State<'_, AccountClients>) -> Result<bool> {
+    Ok(accounts
+        .active()
+        .is_ok_and(|client| client.api_token().is_ok()))
 }
 
+/// Sign out of the active account; other accounts keep their secrets
+/// The account stays in the account list.
 #[tauri::command]
-pub fn logout() -> Result<()> {
-    credentials::delete_api_token()?;
-    credentials::delete_refresh_token()?;
+pub async fn logout(
+    repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    accounts: tauri::State<'_, AccountClients>,
+    vault: tauri::State<'_, CredentialVault>,
+) -> Result<()> {
+    let store = AccountStore::load(repo.inner()).await?;
+    let Ok(account) = store.active_account() else {
+        return Ok(());
+    };
+    let vault = vault.inner();
+    if let Ok(key) = account.credential_key() {
+        credentials::delete_api_token(vault, &key)?;
+        credentials::delete_refresh_token(vault, &key)?;
+    }
+    credentials::delete_legacy_entries(vault, &account.id)?;
+    accounts.remove(&account.id);
+    log::info!("Signed out of account '{}'", account.id);
     Ok(())
 }
 
 #[tauri::command]
-pub async fn refresh_token(client: tauri::State<'_, TaigaClient>) -> Result<()> {
-    let refresh = credentials::get_refresh_token()?;
-    let new_tokens = client.refresh_token(refresh.expose_secret()).await?;
-
-    credentials::set_api_token(&new_tokens.auth_token)?;
-    credentials::set_refresh_token(&new_tokens.refresh)?;
-
-    log::info!("Token refreshed successfully");
-    Ok(())
+pub async fn refresh_token(accounts: tauri::State<'_, AccountClients>) -> Result<()> {
+    let client = accounts.active()?;
+    client.refresh().await
 }
 
 #[tauri::command]
 pub async fn get_taiga_base_url(
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    account_id: Option<String>,
 ) -> Result<String> {
-    let api_url = repo
-        .get_config("taiga_api_url")
-        .await?
-        .ok_or_else(|| crate::error::Error::InvalidInput("Taiga API URL not found".to_string()))?;
-
-    // Transform API URL to web URL
-    // Cloud: https://api.taiga.io -> https://tree.taiga.io
-    // Self-hosted: https://taiga.example.com/api/v1 -> https://taiga.example.com
-    let base_url = if let Ok(parsed) = url::Url::parse(&api_url) {
-        if parsed.host_str() == Some("api.taiga.io") {
-            "https://tree.taiga.io".to_string()
-        } else {
-            // Strip common API path suffixes
-            let mut result = api_url.clone();
-            for suffix in &["/api/v1", "/api/v2", "/api"] {
-                if result.ends_with(suffix) {
-                    result = result.trim_end_matches(suffix).to_string();
-                    break;
-                }
-            }
-            result.trim_end_matches('/').to_string()
-        }
-    } else {
-        api_url.trim_end_matches("/api/v1").trim_end_matches('/').to_string()
-    };
+    let store = AccountStore::load(repo.inner()).await?;
+    let api_url = &match account_id {
+        Some(account_id) => store.get(&account_id)?,
+        None => store.active_account()?,
+    }
+    .api_url;
 
-    Ok(base_url)
+    Ok(issue_links::web_base_url(api_url))
 }
 
 #[tauri::command]
 pub async fn get_taiga_api_url(
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
 ) -> Result<String> {
-    let api_url = repo
-        .get_config("taiga_api_url")
-        .await?
-        .ok_or_else(|| crate::error::Error::InvalidInput("Taiga API URL not found".to_string()))?;
-    Ok(api_url)
+    let store = AccountStore::load(repo.inner()).await?;
+    let api_url = &store.active_account()?.api_url;
+    Ok(api_url.clone())
 }
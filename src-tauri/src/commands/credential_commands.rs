@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+use crate::repositories::{Repository, SqliteRepository};
+use crate::services::accounts::AccountStore;
+use crate::services::credential_store::{
+    CredentialBackend, CredentialStore, CredentialStoreStatus, CredentialVault, KeyringStore,
+    CREDENTIAL_BACKEND_KEY,
+};
+use crate::services::credentials;
+
+/// Which backend holds the tokens, whether the keyring works and whether the file is locked
+/// The frontend offers the encrypted file when `keyring_available` is false and
+/// asks for the passphrase while `locked` is true.
+#[tauri::command]
+pub fn get_credential_store_status(
+    vault: tauri::State<'_, CredentialVault>,
+) -> Result<CredentialStoreStatus> {
+    Ok(vault.status())
+}
+
+/// Unlock the encrypted credential file for this run of the app
+/// Sessions that needed the file, such as a legacy account's, are restored afterwards.
+#[tauri::command]
+pub async fn unlock_credential_store(
+    app: tauri::AppHandle,
+    vault: tauri::State<'_, CredentialVault>,
+    passphrase: String,
+) -> Result<CredentialStoreStatus> {
+    // Deriving the key is slow on purpose; keep it off the async runtime
+    let unlocking = vault.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || unlocking.unlock(&passphrase))
+        .await
+        .map_err(|e| Error::Io(e.to_string()))??;
+    log::info!("Unlocked the credential file");
+    crate::spawn_complete_sessions(app);
+    Ok(vault.status())
+}
+
+/// Keep tokens in `backend` from now on
+/// The encrypted file needs `passphrase` unless it is already unlocked; it is
+/// created on first use. Tokens of known accounts are moved over when the old
+/// backend can still be read, otherwise those accounts have to sign in again.
+#[tauri::command]
+pub async fn set_credential_backend(
+    repo: tauri::State<'_, SqliteRepository>,
+    vault: tauri::State<'_, CredentialVault>,
+    backend: CredentialBackend,
+    passphrase: Option<String>,
+) -> Result<CredentialStoreStatus> {
+    let mut keys = Vec::new();
+    if vault.status().backend != backend {
+        for account in AccountStore::load(repo.inner()).await?.accounts {
+            if let Ok(key) = account.credential_key() {
+                keys.push((account.id, key));
+            }
+        }
+    }
+
+    // Probing the keyring, deriving the file key and rewriting the file all block
+    let switching = vault.inner().clone();
+    let store = tauri::async_runtime::spawn_blocking(move || {
+        let store: Arc<dyn CredentialStore> = match backend {
+            CredentialBackend::Keyring => {
+                KeyringStore::probe()?;
+                Arc::new(KeyringStore)
+            }
+            CredentialBackend::EncryptedFile => {
+                switching.open_file(passphrase.as_deref().unwrap_or_default())?
+            }
+        };
+
+        if let Ok(old) = switching.current() {
+            for (account_id, key) in &keys {
+                if let Err(e) = credentials::move_tokens(old.as_ref(), store.as_ref(), key) {
+                    log::warn!(
+                        "Failed to move the tokens of account '{}': {}",
+                        account_id,
+                        e
+                    );
+                }
+            }
+        }
+        Ok::<_, Error>(store)
+    })
+    .await
+    .map_err(|e| Error::Io(e.to_string()))??;
+
+    let value = serde_json::to_string(&backend)?;
+    repo.save_config(CREDENTIAL_BACKEND_KEY, &value).await?;
+    vault.replace(backend, store);
+    log::info!("Credential backend set to {:?}", backend);
+    Ok(vault.status())
+}
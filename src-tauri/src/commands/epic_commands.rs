@@ -0,0 +1,170 @@
+use crate::commands::project_commands::{dashboard_projects, FilterObject};
+use crate::domain::epic::Epic;
+use crate::domain::issue::Issue;
+use crate::error::{Error, Result};
+use crate::repositories::SqliteRepository;
+use crate::services::accounts::AccountClients;
+use secrecy::Secret;
+use taiga_client::TaigaClient;
+
+/// List the epics of every selected project of every signed-in account
+/// Each epic carries the account it came from, like the dashboard rows.
+#[tauri::command]
+pub async fn get_aggregated_epics(
+    accounts: tauri::State<'_, AccountClients>,
+    repo: tauri::State<'_, SqliteRepository>,
+) -> Result<Vec<Epic>> {
+    let projects = dashboard_projects(repo.inner(), &accounts, &FilterObject::default()).await?;
+    if projects.is_empty() {
+        return Ok(vec![]);
+    }
+
+    async fn fetch_project_epics(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<Epic>> {
+        let epics_dto = client.list_epics(&token, project_id).await?;
+
+        // One stories request per epic, run side by side
+        let mut tasks = Vec::with_capacity(epics_dto.len());
+        for dto in epics_dto {
+            let client = client.clone();
+            let token = token.clone();
+            tasks.push(tauri::async_runtime::spawn(async move {
+                let stories = client.list_epic_user_stories(&token, dto.id).await;
+                (dto, stories)
+            }));
+        }
+
+        let mut epics = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            match task.await {
+                Ok((dto, Ok(stories))) => epics.push(Epic::from_dto(dto, &stories)),
+                Ok((dto, Err(e))) => match Error::from(e) {
+                    // Let the caller refresh the token and retry the project
+                    Error::Unauthorized => return Err(Error::Unauthorized),
+                    e => log::error!("Failed to fetch user stories of epic {}: {}", dto.id, e),
+                },
+                Err(e) => log::error!("Task join error: {}", e),
+            }
+        }
+        Ok(epics)
+    }
+
+    let mut tasks = Vec::new();
+    for project in projects {
+        tasks.push(tauri::async_runtime::spawn(async move {
+            let pid = project.project_id;
+            let epics = project
+                .client
+                .call(|client, token| fetch_project_epics(client, token, pid))
+                .await;
+            (project.account_id, epics)
+        }));
+    }
+
+    let mut all_epics = Vec::new();
+    for task in tasks {
+        match task.await {
+            Ok((account_id, Ok(epics))) => {
+                all_epics.extend(epics.into_iter().map(|mut epic| {
+                    epic.account_id = Some(account_id.clone());
+                    epic
+                }));
+            }
+            Ok((_, Err(e))) => log::error!("Failed to fetch epics: {}", e),
+            Err(e) => log::error!("Task join error: {}", e),
+        }
+    }
+
+    // Keep each project's epics together, in Taiga's ref order; project IDs
+    // only identify a project within one account
+    all_epics.sort_by(|a, b| {
+        (&a.account_id, a.project, a.ref_number).cmp(&(&b.account_id, b.project, b.ref_number))
+    });
+
+    Ok(all_epics)
+}
+
+/// List the user stories related to an epic as dashboard rows
+/// `account_id` is the epic's account; the active account when omitted.
+#[tauri::command]
+pub async fn list_epic_user_stories(
+    accounts: tauri::State<'_, AccountClients>,
+    epic_id: i64,
+    account_id: Option<String>,
+) -> Result<Vec<Issue>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        epic_id: i64,
+    ) -> Result<Vec<Issue>> {
+        let stories_dto = client.list_epic_user_stories(&token, epic_id).await?;
+        Ok(stories_dto.into_iter().map(|s| s.into()).collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    let account_id = account_id.or_else(|| accounts.active_id());
+    let mut stories = client
+        .call(|client, token| fetch(client, token, epic_id))
+        .await?;
+    for story in &mut stories {
+        story.account_id = account_id.clone();
+    }
+    Ok(stories)
+}
+
+/// Link a user story to an epic
+/// `account_id` is the epic's account; the active account when omitted.
+#[tauri::command]
+pub async fn link_user_story_to_epic(
+    accounts: tauri::State<'_, AccountClients>,
+    epic_id: i64,
+    user_story_id: i64,
+    account_id: Option<String>,
+) -> Result<()> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        epic_id: i64,
+        user_story_id: i64,
+    ) -> Result<()> {
+        client
+            .link_epic_user_story(&token, epic_id, user_story_id)
+            .await?;
+        Ok(())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, epic_id, user_story_id))
+        .await
+}
+
+/// Remove a user story from an epic
+/// `account_id` is the epic's account; the active account when omitted.
+#[tauri::command]
+pub async fn unlink_user_story_from_epic(
+    accounts: tauri::State<'_, AccountClients>,
+    epic_id: i64,
+    user_story_id: i64,
+    account_id: Option<String>,
+) -> Result<()> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        epic_id: i64,
+        user_story_id: i64,
+    ) -> Result<()> {
+        client
+            .unlink_epic_user_story(&token, epic_id, user_story_id)
+            .await?;
+        Ok(())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, epic_id, user_story_id))
+        .await
+}
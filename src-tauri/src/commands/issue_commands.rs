@@ -1,40 +1,153 @@
-use crate::domain::issue_detail::{HistoryEntry, IssueDetail};
+use crate::domain::custom_attribute::{merge_attribute_values, IssueCustomAttributes};
+use crate::domain::issue_detail::{HistoryEntry, IssueDetail, Voter};
+use crate::domain::user_story::UserStoryDetail;
 use crate::error::Result;
 use crate::repositories::{Repository, SqliteRepository};
-use crate::services::{credentials, token_refresh};
+use crate::services::accounts::{AccountClients, AccountStore};
+use crate::services::auth_client::AuthClient;
+use crate::services::issue_import::{self, ImportFormat, ImportRow, ImportRowResult};
+use crate::services::issue_links::{self, IssueReference};
+use crate::services::project_metadata;
+use secrecy::Secret;
+use serde::{Deserialize, Serialize};
+use taiga_client::errors::TaigaClientError;
 use taiga_client::TaigaClient;
 
+/// Fields for a new issue; unset optional fields fall back to the project defaults
+#[derive(Debug, Deserialize)]
+pub struct NewIssue {
+    pub project_id: i64,
+    pub subject: String,
+    pub description: Option<String>,
+    pub status_id: Option<i64>,
+    pub type_id: Option<i64>,
+    pub priority_id: Option<i64>,
+    pub severity_id: Option<i64>,
+    pub assignee_id: Option<i64>,
+    #[serde(default)]
+    pub tags: Vec<(String, Option<String>)>,
+    #[serde(default)]
+    pub watcher_ids: Vec<i64>,
+}
+
+/// A file to attach to an issue right after it is created
+#[derive(Debug, Deserialize)]
+pub struct NewAttachment {
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    pub file_data: Vec<u8>,
+}
+
+/// An attachment of a new issue that could not be uploaded, and why
+#[derive(Debug, Serialize)]
+pub struct FailedAttachment {
+    pub file_name: String,
+    pub error: String,
+}
+
+/// A newly created issue and the attachments that did not make it
+#[derive(Debug, Serialize)]
+pub struct CreatedIssue {
+    pub issue: IssueDetail,
+    pub failed_attachments: Vec<FailedAttachment>,
+}
+
+/// The user story created from an issue, and the issue after the follow-up changes
+/// The story exists once promotion succeeds, so follow-up failures are listed in
+/// `follow_up_errors` instead of failing the command: a retry would create a
+/// second story. `user_story` and `issue` are missing when they could not be loaded.
+#[derive(Debug, Serialize)]
+pub struct PromotedIssue {
+    pub project_id: i64,
+    pub user_story_ref: i64,
+    pub user_story: Option<UserStoryDetail>,
+    pub issue: Option<IssueDetail>,
+    /// Whether the issue is closed after the follow-up
+    pub closed: bool,
+    pub follow_up_errors: Vec<String>,
+}
+
 /// Get detailed issue information by ID
 /// This command fetches the full issue details from Taiga API
 #[tauri::command]
 pub async fn get_issue_detail(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
-    async fn fetch(client: &TaigaClient, issue_id: i64) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+    ) -> Result<IssueDetail> {
         let issue_dto = client.get_issue(&token, issue_id).await?;
         Ok(IssueDetail::from_dto(issue_dto))
     }
 
-    match fetch(&client, issue_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id).await
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id))
+        .await
+}
+
+/// Open an issue from a pasted Taiga link or a `project-slug#ref` string
+/// Links must belong to the Taiga instance Larch is signed in to.
+#[tauri::command]
+pub async fn resolve_issue_reference(
+    accounts: tauri::State<'_, AccountClients>,
+    repository: tauri::State<'_, SqliteRepository>,
+    reference: String,
+    account_id: Option<String>,
+) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let store = AccountStore::load(repository.inner()).await?;
+    let api_url = &store.active_account()?.api_url;
+    let reference =
+        issue_links::parse_issue_reference(&reference, &issue_links::web_base_url(api_url))?;
+
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        reference: &IssueReference,
+    ) -> Result<IssueDetail> {
+        let resolved = client
+            .resolve(&token, &reference.project_slug, Some(reference.ref_))
+            .await?;
+        if resolved.issue.is_none() {
+            return Err(crate::error::Error::InvalidInput(format!(
+                "{}#{} is not an issue",
+                reference.project_slug, reference.ref_
+            )));
         }
-        result => result,
+        let issue_dto = client
+            .get_issue_by_ref(&token, resolved.project, reference.ref_)
+            .await?;
+        Ok(IssueDetail::from_dto(issue_dto))
     }
+
+    log::info!(
+        "Resolving issue {}#{}",
+        reference.project_slug,
+        reference.ref_
+    );
+
+    client
+        .call(|client, token| fetch(client, token, &reference))
+        .await
 }
 
 /// Get issue history (comments and changes)
 #[tauri::command]
 pub async fn get_issue_history(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
+    account_id: Option<String>,
 ) -> Result<Vec<HistoryEntry>> {
-    async fn fetch(client: &TaigaClient, issue_id: i64) -> Result<Vec<HistoryEntry>> {
-        let token = credentials::get_api_token()?;
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+    ) -> Result<Vec<HistoryEntry>> {
         let history_dto = client.get_issue_history(&token, issue_id).await?;
         let entries: Vec<HistoryEntry> = history_dto
             .iter()
@@ -44,32 +157,339 @@ pub async fn get_issue_history(
         Ok(entries)
     }
 
-    match fetch(&client, issue_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id).await
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id))
+        .await
+}
+
+/// Create a new issue and optionally upload attachments to it
+/// Once Taiga has created the issue the command succeeds, so a retry cannot
+/// create a duplicate: failed uploads are reported in `failed_attachments`, and
+/// the create response stands in for the detail if that cannot be fetched.
+#[tauri::command]
+pub async fn create_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    issue: NewIssue,
+    attachments: Option<Vec<NewAttachment>>,
+    account_id: Option<String>,
+) -> Result<CreatedIssue> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let subject = issue.subject.trim().to_string();
+    if subject.is_empty() {
+        return Err(crate::error::Error::InvalidInput(
+            "Issue subject cannot be empty".to_string(),
+        ));
+    }
+
+    let request = taiga_client::models::CreateIssueRequest {
+        project: issue.project_id,
+        subject,
+        description: issue.description,
+        status: issue.status_id,
+        type_: issue.type_id,
+        priority: issue.priority_id,
+        severity: issue.severity_id,
+        assigned_to: issue.assignee_id,
+        tags: issue.tags,
+        watchers: issue.watcher_ids,
+    };
+
+    async fn create(
+        client: &TaigaClient,
+        token: Secret<String>,
+        request: &taiga_client::models::CreateIssueRequest,
+    ) -> Result<taiga_client::models::IssueDto> {
+        Ok(client.create_issue(&token, request.clone()).await?)
+    }
+
+    let created = client
+        .call(|client, token| create(client, token, &request))
+        .await?;
+
+    log::info!(
+        "Created issue {} in project {}",
+        created.id,
+        created.project
+    );
+
+    let attachments = attachments.unwrap_or_default();
+    let mut failed_attachments = Vec::new();
+    for attachment in &attachments {
+        if let Err(e) = client
+            .call(|client, token| async move {
+                client
+                    .upload_issue_attachment(
+                        &token,
+                        created.project,
+                        created.id,
+                        attachment.file_name.clone(),
+                        attachment.mime_type.clone(),
+                        attachment.file_data.clone(),
+                    )
+                    .await?;
+                Ok(())
+            })
+            .await
+        {
+            log::error!(
+                "Failed to upload attachment '{}' to new issue {}: {}",
+                attachment.file_name,
+                created.id,
+                e
+            );
+            failed_attachments.push(FailedAttachment {
+                file_name: attachment.file_name.clone(),
+                error: e.to_string(),
+            });
         }
-        result => result,
     }
+
+    let issue = match get_issue_detail(accounts, created.id, account_id).await {
+        Ok(issue) => issue,
+        Err(e) => {
+            log::warn!("Failed to fetch new issue {}: {}", created.id, e);
+            IssueDetail::from_created(created)
+        }
+    };
+    Ok(CreatedIssue {
+        issue,
+        failed_attachments,
+    })
+}
+
+/// Delete an issue after confirming nobody changed it since the user looked at it
+/// Taiga's DELETE has no version check, so the current version is re-fetched and
+/// compared first. Local drafts for the issue are removed afterwards, also when
+/// a retried DELETE finds the issue already gone.
+#[tauri::command]
+pub async fn delete_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    repository: tauri::State<'_, SqliteRepository>,
+    issue_id: i64,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<()> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+        version: i64,
+    ) -> Result<()> {
+        let current = client.get_issue(&token, issue_id).await?;
+        if current.version != version {
+            log::warn!(
+                "Refusing to delete issue {}: version {} does not match current {}",
+                issue_id,
+                version,
+                current.version
+            );
+            return Err(crate::error::Error::VersionConflict);
+        }
+        match client.delete_issue(&token, issue_id).await {
+            // The issue was just fetched, so a 404 means a retried DELETE
+            // found it already removed by an earlier attempt
+            Err(TaigaClientError::Api(api)) if api.status.as_u16() == 404 => {
+                log::info!("Issue {} was already deleted", issue_id);
+                Ok(())
+            }
+            result => Ok(result?),
+        }
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, version))
+        .await?;
+
+    repository
+        .delete_drafts_for(&format!("issue_{}", issue_id))
+        .await?;
+
+    log::info!("Deleted issue {}", issue_id);
+
+    Ok(())
+}
+
+/// Promote an issue to a user story in the same project
+/// Optionally closes the issue and comments on it with a reference to the story.
+/// Promotion and follow-up are retried separately, so a token refresh never
+/// creates the story twice; once the story exists the command always succeeds.
+#[tauri::command]
+pub async fn promote_issue_to_user_story(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    close_issue: bool,
+    add_comment: bool,
+    account_id: Option<String>,
+) -> Result<PromotedIssue> {
+    async fn promote(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+    ) -> Result<(i64, i64)> {
+        let issue_dto = client.get_issue(&token, issue_id).await?;
+        let refs = client
+            .promote_issue_to_user_story(&token, issue_id, issue_dto.project)
+            .await?;
+        let story_ref = refs.first().copied().ok_or_else(|| {
+            crate::error::Error::TaigaClient(
+                "Taiga did not return the promoted user story".to_string(),
+            )
+        })?;
+        Ok((issue_dto.project, story_ref))
+    }
+
+    async fn fetch_story(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        story_ref: i64,
+    ) -> Result<UserStoryDetail> {
+        let story_dto = client
+            .get_user_story_by_ref(&token, project_id, story_ref)
+            .await?;
+        Ok(UserStoryDetail::from_dto(story_dto))
+    }
+
+    /// Close and comment on the issue as asked; the flag tells whether it is closed
+    async fn update_issue(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+        project_id: i64,
+        close_issue: bool,
+        comment: Option<String>,
+    ) -> Result<(IssueDetail, bool)> {
+        // Re-read the issue: promotion may have bumped its version
+        let issue_dto = client.get_issue(&token, issue_id).await?;
+
+        let status = if close_issue && !issue_dto.is_closed {
+            let project = client.get_project(&token, project_id).await?;
+            closed_issue_status(&project.issue_statuses.unwrap_or_default())
+        } else {
+            None
+        };
+
+        if status.is_none() && comment.is_none() {
+            let closed = issue_dto.is_closed;
+            return Ok((IssueDetail::from_dto(issue_dto), closed));
+        }
+
+        let request = taiga_client::models::PatchIssueRequest {
+            version: issue_dto.version,
+            status,
+            comment,
+            description: None,
+            subject: None,
+            assigned_to: None,
+            priority: None,
+            severity: None,
+            type_: None,
+            tags: None,
+        };
+        let updated = client.patch_issue(&token, issue_id, request).await?;
+        let closed = updated.is_closed;
+        Ok((IssueDetail::from_dto(updated), closed))
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    let (project_id, story_ref) = client
+        .call(|client, token| promote(client, token, issue_id))
+        .await?;
+    log::info!(
+        "Promoted issue {} to user story #{} in project {}",
+        issue_id,
+        story_ref,
+        project_id
+    );
+
+    // From here on the story exists: report failures instead of returning them
+    let mut follow_up_errors = Vec::new();
+
+    let user_story = match client
+        .call(|client, token| fetch_story(client, token, project_id, story_ref))
+        .await
+    {
+        Ok(story) => Some(story),
+        Err(e) => {
+            log::error!("Failed to load promoted user story #{}: {}", story_ref, e);
+            follow_up_errors.push(format!("Could not load the user story: {}", e));
+            None
+        }
+    };
+
+    let comment = add_comment.then(|| match &user_story {
+        Some(story) => format!(
+            "Promoted to user story #{} {}",
+            story.ref_number, story.subject
+        ),
+        None => format!("Promoted to user story #{}", story_ref),
+    });
+
+    let (issue, closed) = match client
+        .call(|client, token| {
+            update_issue(
+                client,
+                token,
+                issue_id,
+                project_id,
+                close_issue,
+                comment.clone(),
+            )
+        })
+        .await
+    {
+        Ok((issue, closed)) => (Some(issue), closed),
+        Err(e) => {
+            log::error!("Failed to update promoted issue {}: {}", issue_id, e);
+            follow_up_errors.push(format!("Could not update the issue: {}", e));
+            (None, false)
+        }
+    };
+
+    if close_issue && issue.is_some() && !closed {
+        log::warn!("Project {} has no closed issue status", project_id);
+        follow_up_errors.push("The project has no closed issue status".to_string());
+    }
+
+    Ok(PromotedIssue {
+        project_id,
+        user_story_ref: story_ref,
+        user_story,
+        issue,
+        closed,
+        follow_up_errors,
+    })
+}
+
+/// The closed issue status that comes first in the project's order
+/// Projects usually list "Closed" before other closed states such as "Rejected".
+fn closed_issue_status(statuses: &[taiga_client::models::IssueStatusDto]) -> Option<i64> {
+    statuses
+        .iter()
+        .filter(|s| s.is_closed)
+        .min_by_key(|s| (s.order, s.id))
+        .map(|s| s.id)
 }
 
 /// Change the status of an issue
 /// Uses optimistic locking via the version field
 #[tauri::command]
 pub async fn change_issue_status(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     status_id: i64,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         status_id: i64,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: Some(status_id),
@@ -86,32 +506,29 @@ pub async fn change_issue_status(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, status_id, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, status_id, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, status_id, version))
+        .await
 }
 
 /// Add a comment to an issue
 /// Uses optimistic locking via the version field
 #[tauri::command]
 pub async fn add_issue_comment(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     comment: String,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         comment: &str,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -128,14 +545,10 @@ pub async fn add_issue_comment(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, &comment, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, &comment, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, &comment, version))
+        .await
 }
 
 /// Commit a description change from local draft to Taiga API
@@ -143,11 +556,13 @@ pub async fn add_issue_comment(
 /// On success, deletes the local draft
 #[tauri::command]
 pub async fn commit_issue_description(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     repository: tauri::State<'_, SqliteRepository>,
     issue_id: i64,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
     // Read the draft from the local database
     let related_id = format!("issue_{}", issue_id);
     let draft_type = "description";
@@ -165,11 +580,11 @@ pub async fn commit_issue_description(
 
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         description: &str,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -186,14 +601,9 @@ pub async fn commit_issue_description(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    let result = match fetch(&client, issue_id, &description, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, &description, version).await
-        }
-        result => result,
-    }?;
+    let result = client
+        .call(|client, token| fetch(client, token, issue_id, &description, version))
+        .await?;
 
     // On success, delete the local draft
     repository.delete_draft(&related_id, draft_type).await?;
@@ -208,18 +618,19 @@ pub async fn commit_issue_description(
 /// Pass `None` for assignee_id to unassign
 #[tauri::command]
 pub async fn change_issue_assignee(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     assignee_id: Option<i64>,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         assignee_id: Option<i64>,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -236,30 +647,27 @@ pub async fn change_issue_assignee(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, assignee_id, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, assignee_id, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, assignee_id, version))
+        .await
 }
 
 #[tauri::command]
 pub async fn change_issue_priority(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     priority_id: i64,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         priority_id: i64,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -276,30 +684,27 @@ pub async fn change_issue_priority(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, priority_id, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, priority_id, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, priority_id, version))
+        .await
 }
 
 #[tauri::command]
 pub async fn change_issue_severity(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     severity_id: i64,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         severity_id: i64,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -316,30 +721,27 @@ pub async fn change_issue_severity(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, severity_id, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, severity_id, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, severity_id, version))
+        .await
 }
 
 #[tauri::command]
 pub async fn change_issue_type(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     type_id: i64,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         type_id: i64,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -356,23 +758,21 @@ pub async fn change_issue_type(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, type_id, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, type_id, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, type_id, version))
+        .await
 }
 
 #[tauri::command]
 pub async fn update_issue_tags(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     tags: Vec<(String, Option<String>)>,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
     let tags_json: Vec<serde_json::Value> = tags
         .iter()
         .map(|(name, color)| serde_json::json!([name, color]))
@@ -380,11 +780,11 @@ pub async fn update_issue_tags(
 
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         tags_json: Vec<serde_json::Value>,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -401,25 +801,22 @@ pub async fn update_issue_tags(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, tags_json.clone(), version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, tags_json, version).await
-        }
-        result => result,
-    }
+    client
+        .call(|client, token| fetch(client, token, issue_id, tags_json.clone(), version))
+        .await
 }
 
 #[tauri::command]
 pub async fn upload_issue_attachment(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     project_id: i64,
     issue_id: i64,
     file_name: String,
     mime_type: Option<String>,
     file_data: Vec<u8>,
+    account_id: Option<String>,
 ) -> Result<crate::domain::issue_detail::Attachment> {
+    let client = accounts.resolve(account_id.as_deref())?;
     log::debug!(
         "Command upload_issue_attachment: project={}, issue={}, type={:?}, size={} bytes",
         project_id,
@@ -430,13 +827,13 @@ pub async fn upload_issue_attachment(
 
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         project_id: i64,
         issue_id: i64,
         file_name: &str,
         mime_type: Option<String>,
         file_data: &[u8],
     ) -> Result<crate::domain::issue_detail::Attachment> {
-        let token = credentials::get_api_token()?;
         let attachment_dto = client
             .upload_issue_attachment(
                 &token,
@@ -450,95 +847,82 @@ pub async fn upload_issue_attachment(
         Ok((&attachment_dto).into())
     }
 
-    match fetch(
-        &client,
-        project_id,
-        issue_id,
-        &file_name,
-        mime_type.clone(),
-        &file_data,
-    )
-    .await
-    {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
+    client
+        .call(|client, token| {
             fetch(
-                &client, project_id, issue_id, &file_name, mime_type, &file_data,
+                client,
+                token,
+                project_id,
+                issue_id,
+                &file_name,
+                mime_type.clone(),
+                &file_data,
             )
-            .await
-        }
-        result => result,
-    }
+        })
+        .await
 }
 
 #[tauri::command]
 pub async fn delete_issue_attachment(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     attachment_id: i64,
+    account_id: Option<String>,
 ) -> Result<()> {
-    async fn fetch(client: &TaigaClient, attachment_id: i64) -> Result<()> {
-        let token = credentials::get_api_token()?;
+    async fn fetch(client: &TaigaClient, token: Secret<String>, attachment_id: i64) -> Result<()> {
         client
             .delete_issue_attachment(&token, attachment_id)
             .await?;
         Ok(())
     }
 
-    match fetch(&client, attachment_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, attachment_id).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, attachment_id))
+        .await
 }
 
 #[tauri::command]
 pub async fn get_issue_attachments(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     project_id: i64,
     issue_id: i64,
+    account_id: Option<String>,
 ) -> Result<Vec<crate::domain::issue_detail::Attachment>> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         project_id: i64,
         issue_id: i64,
     ) -> Result<Vec<crate::domain::issue_detail::Attachment>> {
-        let token = credentials::get_api_token()?;
         let attachments_dto = client
             .list_issue_attachments(&token, project_id, issue_id)
             .await?;
         Ok(attachments_dto.iter().map(|a| a.into()).collect())
     }
 
-    match fetch(&client, project_id, issue_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, project_id, issue_id).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, project_id, issue_id))
+        .await
 }
 
 /// Change the subject (title) of an issue
 /// Uses optimistic locking via the version field
 #[tauri::command]
 pub async fn change_issue_subject(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     subject: String,
     version: i64,
+    account_id: Option<String>,
 ) -> Result<IssueDetail> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         subject: &str,
         version: i64,
     ) -> Result<IssueDetail> {
-        let token = credentials::get_api_token()?;
         let request = taiga_client::models::PatchIssueRequest {
             version,
             status: None,
@@ -555,68 +939,431 @@ pub async fn change_issue_subject(
         Ok(IssueDetail::from_dto(updated_issue_dto))
     }
 
-    match fetch(&client, issue_id, &subject, version).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, &subject, version).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, &subject, version))
+        .await
 }
 
 /// Edit an existing issue comment
 #[tauri::command]
 pub async fn edit_issue_comment(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     comment_id: String,
     comment: String,
+    account_id: Option<String>,
 ) -> Result<()> {
     async fn fetch(
         client: &TaigaClient,
+        token: Secret<String>,
         issue_id: i64,
         comment_id: &str,
         comment: &str,
     ) -> Result<()> {
-        let token = credentials::get_api_token()?;
         client
             .edit_issue_comment(&token, issue_id, comment_id.to_string(), comment)
             .await?;
         Ok(())
     }
 
-    match fetch(&client, issue_id, &comment_id, &comment).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, &comment_id, &comment).await
-        }
-        result => result,
-    }
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, &comment_id, &comment))
+        .await
 }
 
 /// Delete an issue comment
 #[tauri::command]
 pub async fn delete_issue_comment(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     issue_id: i64,
     comment_id: String,
+    account_id: Option<String>,
 ) -> Result<()> {
-    async fn fetch(client: &TaigaClient, issue_id: i64, comment_id: &str) -> Result<()> {
-        let token = credentials::get_api_token()?;
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+        comment_id: &str,
+    ) -> Result<()> {
         client
             .delete_issue_comment(&token, issue_id, comment_id.to_string())
             .await?;
         Ok(())
     }
 
-    match fetch(&client, issue_id, &comment_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, issue_id, &comment_id).await
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id, &comment_id))
+        .await
+}
+
+/// Watch and vote toggles exposed on the issue detail
+#[derive(Debug, Clone, Copy)]
+enum IssueAction {
+    Watch,
+    Unwatch,
+    Upvote,
+    Downvote,
+}
+
+/// Apply a watch/vote action, then return the refreshed issue
+/// Taiga answers these endpoints without a body, so the issue is fetched again
+async fn apply_issue_action(
+    client: &AuthClient,
+    issue_id: i64,
+    action: IssueAction,
+) -> Result<IssueDetail> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+        action: IssueAction,
+    ) -> Result<IssueDetail> {
+        match action {
+            IssueAction::Watch => client.watch_issue(&token, issue_id).await?,
+            IssueAction::Unwatch => client.unwatch_issue(&token, issue_id).await?,
+            IssueAction::Upvote => client.upvote_issue(&token, issue_id).await?,
+            IssueAction::Downvote => client.downvote_issue(&token, issue_id).await?,
+        }
+        let issue_dto = client.get_issue(&token, issue_id).await?;
+        Ok(IssueDetail::from_dto(issue_dto))
+    }
+
+    log::info!("Applying {:?} to issue {}", action, issue_id);
+
+    client
+        .call(|client, token| fetch(client, token, issue_id, action))
+        .await
+}
+
+/// Subscribe the current user to an issue's notifications
+#[tauri::command]
+pub async fn watch_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    apply_issue_action(&client, issue_id, IssueAction::Watch).await
+}
+
+/// Unsubscribe the current user from an issue's notifications
+#[tauri::command]
+pub async fn unwatch_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    apply_issue_action(&client, issue_id, IssueAction::Unwatch).await
+}
+
+/// Vote for an issue as the current user
+#[tauri::command]
+pub async fn upvote_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    apply_issue_action(&client, issue_id, IssueAction::Upvote).await
+}
+
+/// Withdraw the current user's vote from an issue
+#[tauri::command]
+pub async fn downvote_issue(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<IssueDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    apply_issue_action(&client, issue_id, IssueAction::Downvote).await
+}
+
+/// List the users who voted for an issue
+#[tauri::command]
+pub async fn get_issue_voters(
+    accounts: tauri::State<'_, AccountClients>,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<Vec<Voter>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        issue_id: i64,
+    ) -> Result<Vec<Voter>> {
+        let voters_dto = client.list_issue_voters(&token, issue_id).await?;
+        Ok(voters_dto.into_iter().map(|v| v.into()).collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, issue_id))
+        .await
+}
+
+/// Get the custom attribute values of an issue, paired with the project's attributes
+#[tauri::command]
+pub async fn get_issue_custom_attributes(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    issue_id: i64,
+    account_id: Option<String>,
+) -> Result<IssueCustomAttributes> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        issue_id: i64,
+    ) -> Result<IssueCustomAttributes> {
+        let attributes = client
+            .get_issue_custom_attributes(&token, project_id)
+            .await?;
+        let values_dto = client
+            .get_issue_custom_attributes_values(&token, issue_id)
+            .await?;
+        Ok(IssueCustomAttributes::from_dtos(&attributes, values_dto))
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, project_id, issue_id))
+        .await
+}
+
+/// Update custom attribute values of an issue
+/// Only the given attributes change; a `null` value clears the attribute.
+/// Uses optimistic locking via the version of the values (not of the issue)
+#[tauri::command]
+pub async fn update_issue_custom_attributes(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    issue_id: i64,
+    values: std::collections::HashMap<i64, serde_json::Value>,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<IssueCustomAttributes> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        issue_id: i64,
+        values: &std::collections::HashMap<i64, serde_json::Value>,
+        version: i64,
+    ) -> Result<IssueCustomAttributes> {
+        let current = client
+            .get_issue_custom_attributes_values(&token, issue_id)
+            .await?;
+        // The caller's version is sent as-is, so edits made since it was read still conflict
+        let request = taiga_client::models::PatchIssueCustomAttributesValuesRequest {
+            version,
+            attributes_values: merge_attribute_values(&current.attributes_values, values),
+        };
+        let updated_dto = client
+            .patch_issue_custom_attributes_values(&token, issue_id, request)
+            .await?;
+        let attributes = client
+            .get_issue_custom_attributes(&token, project_id)
+            .await?;
+        Ok(IssueCustomAttributes::from_dtos(&attributes, updated_dto))
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    log::info!(
+        "Updating {} custom attribute(s) of issue {} (version {})",
+        values.len(),
+        issue_id,
+        version
+    );
+
+    client
+        .call(|client, token| fetch(client, token, project_id, issue_id, &values, version))
+        .await
+}
+
+/// Import issues from a CSV file or a Markdown checklist, one result per row
+/// Files ending in `.csv` are read as CSV; anything else as Markdown.
+#[tauri::command]
+pub async fn import_issues_from_file(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    file_path: String,
+    account_id: Option<String>,
+) -> Result<Vec<ImportRowResult>> {
+    let path = std::path::PathBuf::from(&file_path);
+    let read_path = path.clone();
+    let data = tauri::async_runtime::spawn_blocking(move || std::fs::read(read_path))
+        .await
+        .map_err(|e| crate::error::Error::Io(e.to_string()))??;
+    let rows = issue_import::parse(&data, ImportFormat::from_path(&path))?;
+
+    log::info!(
+        "Importing {} issue(s) from {} into project {}",
+        rows.len(),
+        file_path,
+        project_id
+    );
+
+    import_rows(accounts, account_id, project_id, rows).await
+}
+
+/// Import issues from pasted text: a Markdown checklist or one subject per line
+#[tauri::command]
+pub async fn import_issues_from_text(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    text: String,
+    account_id: Option<String>,
+) -> Result<Vec<ImportRowResult>> {
+    let rows = issue_import::parse(text.as_bytes(), ImportFormat::Markdown)?;
+
+    log::info!(
+        "Importing {} pasted issue(s) into project {}",
+        rows.len(),
+        project_id
+    );
+
+    import_rows(accounts, account_id, project_id, rows).await
+}
+
+/// Create the parsed rows, reporting success or failure for each one
+/// Subject-only rows share a single bulk request; rows with attributes are
+/// created one by one so their names can be resolved to IDs.
+async fn import_rows(
+    accounts: tauri::State<'_, AccountClients>,
+    account_id: Option<String>,
+    project_id: i64,
+    rows: Vec<ImportRow>,
+) -> Result<Vec<ImportRowResult>> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    if rows.is_empty() {
+        return Err(crate::error::Error::InvalidInput(
+            "No issues found to import".to_string(),
+        ));
+    }
+
+    // Names are resolved against the same metadata the issue forms use
+    let metadata = client
+        .call(|client, token| project_metadata::fetch_project_metadata(client, token, project_id))
+        .await?;
+
+    async fn create(
+        client: &TaigaClient,
+        token: Secret<String>,
+        request: &taiga_client::models::CreateIssueRequest,
+    ) -> Result<taiga_client::models::IssueDto> {
+        Ok(client.create_issue(&token, request.clone()).await?)
+    }
+
+    async fn bulk_create(
+        client: &TaigaClient,
+        token: Secret<String>,
+        request: &taiga_client::models::BulkCreateIssuesRequest,
+    ) -> Result<Vec<taiga_client::models::IssueDto>> {
+        Ok(client.bulk_create_issues(&token, request.clone()).await?)
+    }
+
+    let mut results: Vec<Option<ImportRowResult>> = vec![None; rows.len()];
+    let mut bulk_rows = Vec::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let request = match issue_import::resolve_row(row, project_id, &metadata) {
+            Ok(request) => request,
+            Err(e) => {
+                results[index] = Some(ImportRowResult::failed(row, e));
+                continue;
+            }
+        };
+
+        if row.is_subject_only() {
+            bulk_rows.push(index);
+            continue;
+        }
+
+        let created = client
+            .call(|client, token| create(client, token, &request))
+            .await;
+        results[index] = Some(match created {
+            Ok(issue) => ImportRowResult::created(row, issue.id),
+            Err(e) => {
+                log::error!("Failed to import line {}: {}", row.line, e);
+                ImportRowResult::failed(row, e.to_string())
+            }
+        });
+    }
+
+    if !bulk_rows.is_empty() {
+        let request = taiga_client::models::BulkCreateIssuesRequest {
+            project_id,
+            bulk_issues: bulk_rows
+                .iter()
+                .map(|&i| rows[i].subject.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+
+        let created = client
+            .call(|client, token| bulk_create(client, token, &request))
+            .await;
+
+        // Taiga returns the created issues in the order of the submitted lines
+        match created {
+            Ok(issues) => {
+                for (position, &index) in bulk_rows.iter().enumerate() {
+                    let row = &rows[index];
+                    results[index] = Some(match issues.get(position) {
+                        Some(issue) => ImportRowResult::created(row, issue.id),
+                        None => ImportRowResult::failed(row, "Taiga did not create this issue"),
+                    });
+                }
+            }
+            Err(e) => {
+                log::error!("Bulk import failed: {}", e);
+                for &index in &bulk_rows {
+                    results[index] = Some(ImportRowResult::failed(&rows[index], e.to_string()));
+                }
+            }
+        }
+    }
+
+    let results: Vec<ImportRowResult> = results.into_iter().flatten().collect();
+    log::info!(
+        "Imported {} of {} issue(s) into project {}",
+        results.iter().filter(|r| r.issue_id.is_some()).count(),
+        results.len(),
+        project_id
+    );
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taiga_client::models::IssueStatusDto;
+
+    fn status(id: i64, order: i64, is_closed: bool) -> IssueStatusDto {
+        IssueStatusDto {
+            id,
+            name: format!("Status {}", id),
+            color: "#000".to_string(),
+            is_closed,
+            order,
         }
-        result => result,
+    }
+
+    #[test]
+    fn test_closed_issue_status_follows_project_order() {
+        let statuses = vec![
+            status(1, 1, false),
+            status(6, 6, true),
+            status(4, 4, true),
+            status(5, 5, false),
+        ];
+
+        assert_eq!(closed_issue_status(&statuses), Some(4));
+        assert_eq!(closed_issue_status(&statuses[..1]), None);
     }
 }
@@ -0,0 +1,56 @@
+use crate::domain::milestone::{Milestone, MilestoneStats};
+use crate::error::Result;
+use crate::services::accounts::AccountClients;
+use secrecy::Secret;
+use taiga_client::TaigaClient;
+
+/// List a project's sprints, flagging the one running today
+/// Closed sprints are left out unless `include_closed` is set
+#[tauri::command]
+pub async fn list_project_milestones(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    include_closed: Option<bool>,
+) -> Result<Vec<Milestone>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        include_closed: bool,
+    ) -> Result<Vec<Milestone>> {
+        let closed = if include_closed { None } else { Some(false) };
+        let milestones_dto = client.list_milestones(&token, project_id, closed).await?;
+        let today = chrono::Local::now().date_naive();
+        Ok(milestones_dto
+            .into_iter()
+            .map(|m| Milestone::from_dto(m, today))
+            .collect())
+    }
+
+    let client = accounts.active()?;
+    let include_closed = include_closed.unwrap_or(false);
+    client
+        .call(|client, token| fetch(client, token, project_id, include_closed))
+        .await
+}
+
+/// Get progress statistics and the burndown of a sprint
+#[tauri::command]
+pub async fn get_milestone_stats(
+    accounts: tauri::State<'_, AccountClients>,
+    milestone_id: i64,
+) -> Result<MilestoneStats> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        milestone_id: i64,
+    ) -> Result<MilestoneStats> {
+        let stats_dto = client.get_milestone_stats(&token, milestone_id).await?;
+        Ok(stats_dto.into())
+    }
+
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, milestone_id))
+        .await
+}
@@ -1,9 +1,17 @@
 // This module will contain all the tauri commands.
 
+pub mod account_commands;
 pub mod app_commands;
 pub mod auth_commands;
+pub mod credential_commands;
 pub mod draft_commands;
+pub mod epic_commands;
 pub mod issue_commands;
+pub mod milestone_commands;
 pub mod project_commands;
+pub mod task_commands;
+pub mod transfer_commands;
 pub mod user_commands;
+pub mod user_story_commands;
 pub mod view_commands;
+pub mod wiki_commands;
@@ -1,405 +1,687 @@
-use crate::domain::issue::Issue;
+use crate::domain::facets::{FilterId, IssueFacets};
+use crate::domain::issue::{Issue, ItemKind};
+use crate::domain::issue_stats::IssueStatsDashboard;
+use crate::domain::milestone::current_milestone_id;
 use crate::domain::project::Project;
 use crate::error::Result;
 use crate::repositories::Repository;
-use crate::services::{credentials, token_refresh};
+use crate::services::accounts::{AccountClients, AccountStore};
+use crate::services::auth_client::AuthClient;
+use crate::services::project_metadata;
+use futures_util::stream::{self, StreamExt};
+use secrecy::Secret;
+use taiga_client::models::{IssueFiltersDataDto, ProjectIssuesStatsDto, SearchItemDto};
 use taiga_client::TaigaClient;
 
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+/// Dashboard filters from the UI
+/// IDs are numbered per Taiga instance, so each one only applies to the requests
+/// of its own account (see `FilterId`).
+#[derive(Debug, Default, Deserialize)]
 pub struct FilterObject {
-    pub status_ids: Option<Vec<i64>>,
+    pub status_ids: Option<Vec<FilterId>>,
     pub status_exclude: Option<bool>,
-    pub assignee_ids: Option<Vec<i64>>,
+    /// `-1` stands for unassigned, on every account
+    pub assignee_ids: Option<Vec<FilterId>>,
     pub assignee_exclude: Option<bool>,
-    pub project_ids: Option<Vec<i64>>,
+    pub project_ids: Option<Vec<FilterId>>,
     pub project_exclude: Option<bool>,
-    pub priority_ids: Option<Vec<i64>>,
+    pub priority_ids: Option<Vec<FilterId>>,
     pub priority_exclude: Option<bool>,
-    pub severity_ids: Option<Vec<i64>>,
+    pub severity_ids: Option<Vec<FilterId>>,
     pub severity_exclude: Option<bool>,
-    pub type_ids: Option<Vec<i64>>,
+    pub type_ids: Option<Vec<FilterId>>,
     pub type_exclude: Option<bool>,
+    /// Also list user stories from the selected projects
+    pub include_user_stories: Option<bool>,
+    /// User story statuses; `status_ids` are issue statuses and do not apply to stories
+    pub user_story_status_ids: Option<Vec<FilterId>>,
+    pub user_story_status_exclude: Option<bool>,
+    /// Only items planned in this sprint
+    pub milestone_id: Option<FilterId>,
+    /// Only items planned in each project's running sprint; takes precedence over `milestone_id`
+    pub current_milestone: Option<bool>,
 }
 
+/// Query keys that also apply to /userstories
+/// Status keys are left out: issue and user story statuses are separate ID spaces.
+const USER_STORY_FILTER_KEYS: [&str; 3] = ["assigned_to", "exclude_assigned_to", "milestone"];
+
+/// Include filters on fields that user stories do not have
+const ISSUE_ONLY_FILTER_KEYS: [&str; 3] = ["priority", "severity", "type"];
+
+/// Search hits whose full rows are fetched at the same time
+const SEARCH_HIT_CONCURRENCY: usize = 8;
+
 #[tauri::command]
-pub async fn get_projects(client: tauri::State<'_, TaigaClient>) -> Result<Vec<Project>> {
-    async fn fetch(client: &TaigaClient) -> Result<Vec<Project>> {
-        let token = credentials::get_api_token()?;
+pub async fn get_projects(accounts: tauri::State<'_, AccountClients>) -> Result<Vec<Project>> {
+    async fn fetch(client: &TaigaClient, token: Secret<String>) -> Result<Vec<Project>> {
         let me = client.get_me(&token).await?;
         let projects_dto = client.get_projects(&token, Some(me.id)).await?;
         Ok(projects_dto.into_iter().map(|p| p.into()).collect())
     }
 
-    match fetch(&client).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client).await
-        }
-        result => result,
-    }
+    let client = accounts.active()?;
+    client.call(fetch).await
 }
 
 #[tauri::command]
 pub async fn list_issues(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     project_id: i64,
 ) -> Result<Vec<Issue>> {
-    async fn fetch(client: &TaigaClient, project_id: i64) -> Result<Vec<Issue>> {
-        let token = credentials::get_api_token()?;
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<Issue>> {
         let issues_dto = client.list_issues(&token, project_id, None).await?;
         Ok(issues_dto.into_iter().map(|i| i.into()).collect())
     }
 
-    match fetch(&client, project_id).await {
-        Err(crate::error::Error::Unauthorized) => {
-            log::info!("Unauthorized, attempting token refresh");
-            token_refresh::refresh_token(&client).await?;
-            fetch(&client, project_id).await
-        }
-        result => result,
-    }
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, project_id))
+        .await
 }
 
 #[tauri::command]
 pub async fn get_aggregated_issues(
-    client: tauri::State<'_, TaigaClient>,
+    accounts: tauri::State<'_, AccountClients>,
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
     filters: FilterObject,
 ) -> Result<Vec<Issue>> {
-    // 1. Get selected projects from DB
-    let selected_ids_opt = repo.get_config("selected_projects").await?;
-    let mut target_project_ids: Vec<i64> = if let Some(val) = selected_ids_opt {
-        serde_json::from_str(&val).unwrap_or_default()
-    } else {
-        vec![]
-    };
-
-    // 2. Apply UI project filter if present
-    if let Some(ref ui_project_ids) = filters.project_ids {
-        if filters.project_exclude.unwrap_or(false) {
-            // Exclude these projects - keep ones NOT in ui_project_ids
-            target_project_ids.retain(|id| !ui_project_ids.contains(id));
-        } else {
-            // Include only these projects - keep ones IN ui_project_ids
-            target_project_ids.retain(|id| ui_project_ids.contains(id));
-        }
-    }
-
-    if target_project_ids.is_empty() {
+    let target_projects = dashboard_projects(&repo, &accounts, &filters).await?;
+    if target_projects.is_empty() {
         return Ok(vec![]);
     }
 
-    // 3. Construct Taiga filters
-    let mut query_params = Vec::new();
-    if let Some(status_ids) = filters.status_ids {
-        if !status_ids.is_empty() {
-            let key = if filters.status_exclude.unwrap_or(false) {
-                "exclude_status"
-            } else {
-                "status"
+    let current_milestone = filters.current_milestone.unwrap_or(false);
+    let include_user_stories = filters.include_user_stories.unwrap_or(false);
+
+    async fn fetch_project_items(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        query_params: &[(String, String)],
+        story_query_params: Option<&[(String, String)]>,
+        current_milestone: bool,
+    ) -> Result<Vec<Issue>> {
+        let mut query_params = query_params.to_vec();
+        let mut story_query_params = story_query_params.map(<[_]>::to_vec);
+        if current_milestone {
+            let milestones = client
+                .list_milestones(&token, project_id, Some(false))
+                .await?;
+            let today = chrono::Local::now().date_naive();
+            let Some(milestone_id) = current_milestone_id(&milestones, today) else {
+                log::info!("Project {} has no running sprint", project_id);
+                return Ok(vec![]);
             };
-            let val = status_ids
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            query_params.push((key.to_string(), val));
+            let param = ("milestone".to_string(), milestone_id.to_string());
+            if let Some(ref mut story_params) = story_query_params {
+                story_params.push(param.clone());
+            }
+            query_params.push(param);
         }
+
+        let mut items: Vec<Issue> = client
+            .list_issues(&token, project_id, Some(query_params))
+            .await?
+            .into_iter()
+            .map(Issue::from)
+            .collect();
+
+        if let Some(story_params) = story_query_params {
+            let stories = client
+                .list_user_stories(&token, project_id, Some(story_params))
+                .await?;
+            items.extend(stories.into_iter().map(Issue::from));
+        }
+
+        Ok(items)
     }
 
-    if let Some(assignee_ids) = filters.assignee_ids {
-        if !assignee_ids.is_empty() {
-            let key = if filters.assignee_exclude.unwrap_or(false) {
-                "exclude_assigned_to"
-            } else {
-                "assigned_to"
-            };
-            let val = assignee_ids
-                .iter()
-                .map(|id| {
-                    if *id == -1 {
-                        "null".to_string()
-                    } else {
-                        id.to_string()
+    // Fetch concurrently; a rejected token is refreshed once per account
+    let mut tasks = Vec::new();
+    for project in target_projects {
+        // Filter IDs are per instance; skip projects whose account has none of
+        // the included options
+        let Some(params) = issue_query_params(&filters, &project.scope()) else {
+            continue;
+        };
+        let story_params =
+            user_story_query_params(include_user_stories, &params).and_then(|mut story_params| {
+                match user_story_status_param(&filters, &project.scope()) {
+                    ScopedFilter::Unset => Some(story_params),
+                    ScopedFilter::Param(key, val) => {
+                        story_params.push((key, val));
+                        Some(story_params)
                     }
+                    ScopedFilter::NoMatch => None,
+                }
+            });
+        tasks.push(tauri::async_runtime::spawn(async move {
+            let mut items = project
+                .client
+                .call(|client, token| {
+                    fetch_project_items(
+                        client,
+                        token,
+                        project.project_id,
+                        &params,
+                        story_params.as_deref(),
+                        current_milestone,
+                    )
                 })
-                .collect::<Vec<_>>()
-                .join(",");
-            query_params.push((key.to_string(), val));
+                .await?;
+            for item in &mut items {
+                item.account_id = Some(project.account_id.clone());
+            }
+            Ok::<_, crate::error::Error>(items)
+        }));
+    }
+
+    let mut all_issues = Vec::new();
+    for task in tasks {
+        match task.await {
+            Ok(Ok(issues)) => all_issues.extend(issues),
+            Ok(Err(e)) => log::error!("Failed to fetch issues: {}", e),
+            Err(e) => log::error!("Task join error: {}", e),
         }
     }
 
-    if let Some(priority_ids) = filters.priority_ids {
-        if !priority_ids.is_empty() {
-            let key = if filters.priority_exclude.unwrap_or(false) {
-                "exclude_priority"
-            } else {
-                "priority"
-            };
-            let val = priority_ids
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            query_params.push((key.to_string(), val));
+    // Sort globally by modified_date (newest first) AFTER aggregation
+    sort_newest_first(&mut all_issues);
+
+    Ok(all_issues)
+}
+
+/// Dashboard order: newest modified_date first, rows without a date last
+fn sort_newest_first(issues: &mut [Issue]) {
+    issues.sort_by(|a, b| {
+        match (&a.modified_date, &b.modified_date) {
+            (Some(date_a), Some(date_b)) => date_b.cmp(date_a), // Descending (newest first)
+            (Some(_), None) => std::cmp::Ordering::Less,        // Issues with dates come first
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    });
+}
+
+/// A selected project and the signed-in account it belongs to
+pub(crate) struct AccountProject {
+    pub(crate) account_id: String,
+    is_active: bool,
+    pub(crate) client: AuthClient,
+    pub(crate) project_id: i64,
+}
+
+impl AccountProject {
+    fn scope(&self) -> AccountScope<'_> {
+        AccountScope {
+            account_id: &self.account_id,
+            is_active: self.is_active,
         }
     }
+}
 
-    if let Some(severity_ids) = filters.severity_ids {
-        if !severity_ids.is_empty() {
-            let key = if filters.severity_exclude.unwrap_or(false) {
-                "exclude_severity"
-            } else {
-                "severity"
-            };
-            let val = severity_ids
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            query_params.push((key.to_string(), val));
+/// The account a request is made for, to pick out its own filter IDs
+struct AccountScope<'a> {
+    account_id: &'a str,
+    is_active: bool,
+}
+
+impl AccountScope<'_> {
+    /// The ID when it belongs to this account's instance
+    /// Plain IDs belong to the active account; `-1` (unassigned) applies everywhere.
+    fn own(&self, id: &FilterId) -> Option<i64> {
+        match id {
+            FilterId::Plain(-1) => Some(-1),
+            FilterId::Plain(id) => self.is_active.then_some(*id),
+            FilterId::Scoped(scoped) if scoped.id == -1 || scoped.account_id == self.account_id => {
+                Some(scoped.id)
+            }
+            FilterId::Scoped(_) => None,
         }
     }
+}
 
-    if let Some(type_ids) = filters.type_ids {
-        if !type_ids.is_empty() {
-            let key = if filters.type_exclude.unwrap_or(false) {
-                "exclude_type"
+/// An ID filter narrowed to one account
+#[derive(Debug, PartialEq)]
+enum ScopedFilter {
+    /// No IDs selected; nothing to send
+    Unset,
+    Param(String, String),
+    /// Only other accounts' options are included, so nothing here can match
+    NoMatch,
+}
+
+/// The `key` or `exclude_key` parameter for this account's share of `ids`
+fn scoped_id_filter(
+    key: &str,
+    ids: Option<&Vec<FilterId>>,
+    exclude: Option<bool>,
+    scope: &AccountScope,
+) -> ScopedFilter {
+    let Some(ids) = ids.filter(|ids| !ids.is_empty()) else {
+        return ScopedFilter::Unset;
+    };
+    let own: Vec<String> = ids
+        .iter()
+        .filter_map(|id| scope.own(id))
+        .map(|id| {
+            if id == -1 {
+                "null".to_string()
             } else {
-                "type"
-            };
-            let val = type_ids
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            query_params.push((key.to_string(), val));
-        }
+                id.to_string()
+            }
+        })
+        .collect();
+
+    match (own.is_empty(), exclude.unwrap_or(false)) {
+        (true, true) => ScopedFilter::Unset,
+        (true, false) => ScopedFilter::NoMatch,
+        (false, true) => ScopedFilter::Param(format!("exclude_{}", key), own.join(",")),
+        (false, false) => ScopedFilter::Param(key.to_string(), own.join(",")),
     }
+}
 
-    // 4. Fetch concurrently with token refresh support
-    async fn fetch_all_issues(
-        client: &TaigaClient,
-        project_ids: &[i64],
-        query_params: &[(String, String)],
-    ) -> (Vec<taiga_client::models::IssueDto>, bool) {
-        let token = match credentials::get_api_token() {
-            Ok(t) => t,
-            Err(_) => return (vec![], false),
+/// Selected projects of every signed-in account, narrowed by the UI project filter
+/// This is what the aggregated dashboard shows, so it can span several Taiga instances.
+pub(crate) async fn dashboard_projects(
+    repo: &crate::repositories::SqliteRepository,
+    accounts: &AccountClients,
+    filters: &FilterObject,
+) -> Result<Vec<AccountProject>> {
+    let store = AccountStore::load(repo).await?;
+    let active_id = store.active.clone();
+    let mut projects = Vec::new();
+    for account in store.accounts {
+        let Ok(client) = accounts.get(&account.id) else {
+            continue;
         };
+        let is_active = active_id.as_deref() == Some(account.id.as_str());
+        let scope = AccountScope {
+            account_id: &account.id,
+            is_active,
+        };
+        for project_id in apply_project_filter(account.selected_projects.clone(), filters, &scope) {
+            projects.push(AccountProject {
+                account_id: account.id.clone(),
+                is_active,
+                client: client.clone(),
+                project_id,
+            });
+        }
+    }
+    Ok(projects)
+}
 
-        let mut tasks = Vec::new();
-        for pid in project_ids {
-            let client = client.clone();
-            let token = token.clone();
-            let params: Vec<(String, String)> = query_params.to_vec();
-            let pid = *pid;
-            tasks.push(tauri::async_runtime::spawn(async move {
-                client.list_issues(&token, pid, Some(params)).await
-            }));
+fn apply_project_filter(
+    mut project_ids: Vec<i64>,
+    filters: &FilterObject,
+    scope: &AccountScope,
+) -> Vec<i64> {
+    if let Some(ref ui_project_ids) = filters.project_ids {
+        let ui_project_ids: Vec<i64> = ui_project_ids
+            .iter()
+            .filter_map(|id| scope.own(id))
+            .collect();
+        if filters.project_exclude.unwrap_or(false) {
+            // Exclude these projects - keep ones NOT in ui_project_ids
+            project_ids.retain(|id| !ui_project_ids.contains(id));
+        } else {
+            // Include only these projects - keep ones IN ui_project_ids
+            project_ids.retain(|id| ui_project_ids.contains(id));
         }
+    }
+    project_ids
+}
 
-        let mut all_issues = Vec::new();
-        let mut had_unauthorized = false;
-        for task in tasks {
-            match task.await {
-                Ok(Ok(issues)) => all_issues.extend(issues),
-                Ok(Err(taiga_client::errors::TaigaClientError::Unauthorized(_))) => {
-                    had_unauthorized = true;
-                }
-                Ok(Err(e)) => log::error!("Failed to fetch issues: {}", e),
-                Err(e) => log::error!("Task join error: {}", e),
-            }
+/// Translate the UI filters into Taiga /issues query parameters for one account
+/// `None` when an include filter only selects other accounts' options.
+/// `current_milestone` needs a per-project lookup and is left to the caller.
+fn issue_query_params(
+    filters: &FilterObject,
+    scope: &AccountScope,
+) -> Option<Vec<(String, String)>> {
+    let id_filters = [
+        ("status", &filters.status_ids, filters.status_exclude),
+        (
+            "assigned_to",
+            &filters.assignee_ids,
+            filters.assignee_exclude,
+        ),
+        ("priority", &filters.priority_ids, filters.priority_exclude),
+        ("severity", &filters.severity_ids, filters.severity_exclude),
+        ("type", &filters.type_ids, filters.type_exclude),
+    ];
+
+    let mut query_params = Vec::new();
+    for (key, ids, exclude) in id_filters {
+        match scoped_id_filter(key, ids.as_ref(), exclude, scope) {
+            ScopedFilter::Unset => {}
+            ScopedFilter::Param(key, val) => query_params.push((key, val)),
+            ScopedFilter::NoMatch => return None,
         }
+    }
 
-        (all_issues, had_unauthorized)
+    let current_milestone = filters.current_milestone.unwrap_or(false);
+    if let (Some(milestone_id), false) = (&filters.milestone_id, current_milestone) {
+        let milestone_id = scope.own(milestone_id)?;
+        query_params.push(("milestone".to_string(), milestone_id.to_string()));
     }
 
-    let (mut all_issues, had_unauthorized) =
-        fetch_all_issues(&client, &target_project_ids, &query_params).await;
+    Some(query_params)
+}
 
-    if had_unauthorized {
-        log::info!("Unauthorized detected in batch fetch, attempting token refresh");
-        token_refresh::refresh_token(&client).await?;
-        let (retried_issues, _) =
-            fetch_all_issues(&client, &target_project_ids, &query_params).await;
-        all_issues = retried_issues;
+/// Derive the /userstories query from the issue query, or `None` when stories are left out
+/// User stories only share the status and assignee filters. An include filter on
+/// priority, severity or type can never match a story, so stories are skipped.
+fn user_story_query_params(
+    include_user_stories: bool,
+    issue_query_params: &[(String, String)],
+) -> Option<Vec<(String, String)>> {
+    if !include_user_stories
+        || issue_query_params
+            .iter()
+            .any(|(key, _)| ISSUE_ONLY_FILTER_KEYS.contains(&key.as_str()))
+    {
+        return None;
     }
 
-    // Sort globally by modified_date (newest first) AFTER aggregation
-    all_issues.sort_by(|a, b| {
-        match (&a.modified_date, &b.modified_date) {
-            (Some(date_a), Some(date_b)) => date_b.cmp(date_a), // Descending (newest first)
-            (Some(_), None) => std::cmp::Ordering::Less,        // Issues with dates come first
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => std::cmp::Ordering::Equal,
-        }
-    });
+    Some(
+        issue_query_params
+            .iter()
+            .filter(|(key, _)| USER_STORY_FILTER_KEYS.contains(&key.as_str()))
+            .cloned()
+            .collect(),
+    )
+}
 
-    Ok(all_issues.into_iter().map(|i| i.into()).collect())
+/// The /userstories status filter for the UI's user story statuses on one account
+fn user_story_status_param(filters: &FilterObject, scope: &AccountScope) -> ScopedFilter {
+    scoped_id_filter(
+        "status",
+        filters.user_story_status_ids.as_ref(),
+        filters.user_story_status_exclude,
+        scope,
+    )
 }
 
+/// Per-option issue counts for the filter panel, summed across the filtered projects
+/// Counts honour the other active filters; statuses and other per-project options
+/// are grouped by name.
 #[tauri::command]
-pub async fn get_project_metadata(
-    client: tauri::State<'_, TaigaClient>,
-    project_ids: Vec<i64>,
-) -> Result<std::collections::HashMap<i64, crate::domain::project::ProjectMetadata>> {
-    use crate::domain::project::{IssueStatus, IssueType, Member, Priority, Severity, TagColor};
+pub async fn get_issue_facets(
+    accounts: tauri::State<'_, AccountClients>,
+    repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    filters: FilterObject,
+) -> Result<IssueFacets> {
+    let projects = dashboard_projects(&repo, &accounts, &filters).await?;
+    let current_milestone = filters.current_milestone.unwrap_or(false);
 
-    let token = credentials::get_api_token()?;
-    let mut tasks = Vec::new();
+    async fn fetch_project_facets(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        query_params: &[(String, String)],
+        current_milestone: bool,
+    ) -> Result<Option<IssueFiltersDataDto>> {
+        let mut query_params = query_params.to_vec();
+        if current_milestone {
+            let milestones = client
+                .list_milestones(&token, project_id, Some(false))
+                .await?;
+            let today = chrono::Local::now().date_naive();
+            let Some(milestone_id) = current_milestone_id(&milestones, today) else {
+                return Ok(None);
+            };
+            query_params.push(("milestone".to_string(), milestone_id.to_string()));
+        }
 
-    for pid in project_ids {
-        let client = client.inner().clone();
-        let token = token.clone();
+        let facets = client
+            .get_issue_filters_data(&token, project_id, Some(query_params))
+            .await?;
+        Ok(Some(facets))
+    }
+
+    let mut tasks = Vec::new();
+    for project in projects {
+        let Some(params) = issue_query_params(&filters, &project.scope()) else {
+            continue;
+        };
         tasks.push(tauri::async_runtime::spawn(async move {
-            let project_res = client.get_project(&token, pid).await;
+            let facets = project
+                .client
+                .call(|client, token| {
+                    fetch_project_facets(
+                        client,
+                        token,
+                        project.project_id,
+                        &params,
+                        current_milestone,
+                    )
+                })
+                .await?;
+            Ok::<_, crate::error::Error>(facets.map(|facets| (project.account_id, facets)))
+        }));
+    }
 
-            let priorities_res = match client.get_priorities(&token, pid).await {
-                Ok(p) => Ok(p),
-                Err(e) => {
-                    log::error!("get_priorities failed for {}: {}", pid, e);
-                    Err(e)
-                }
-            };
+    let mut all_facets = Vec::new();
+    for task in tasks {
+        match task.await {
+            Ok(Ok(facets)) => all_facets.extend(facets),
+            Ok(Err(e)) => log::error!("Failed to fetch filters data: {}", e),
+            Err(e) => log::error!("Task join error: {}", e),
+        }
+    }
 
-            let severities_res = match client.get_severities(&token, pid).await {
-                Ok(s) => Ok(s),
-                Err(e) => {
-                    log::error!("get_severities failed for {}: {}", pid, e);
-                    Err(e)
-                }
-            };
+    Ok(IssueFacets::merge(all_facets))
+}
 
-            let types_res = match client.get_issue_types(&token, pid).await {
-                Ok(t) => Ok(t),
-                Err(e) => {
-                    log::error!("get_issue_types failed for {}: {}", pid, e);
-                    Err(e)
-                }
-            };
+/// Issue statistics for each project of a saved view, plus their combined totals
+/// Covers the same accounts as the dashboard. Taiga's stats cover whole projects,
+/// so only the view's project filter applies.
+#[tauri::command]
+pub async fn get_view_issue_stats(
+    accounts: tauri::State<'_, AccountClients>,
+    repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    view_id: i32,
+) -> Result<IssueStatsDashboard> {
+    let view = repo
+        .get_view(view_id)
+        .await?
+        .ok_or_else(|| crate::error::Error::InvalidInput(format!("View {} not found", view_id)))?;
+    let filters: FilterObject = serde_json::from_str(&view.filter_data)?;
+    let projects = dashboard_projects(&repo, &accounts, &filters).await?;
+
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<(i64, ProjectIssuesStatsDto)> {
+        let stats = client.get_project_issues_stats(&token, project_id).await?;
+        Ok((project_id, stats))
+    }
 
-            let tags_res = match client.get_project_tags_colors(&token, pid).await {
-                Ok(t) => Ok(t),
-                Err(e) => {
-                    log::error!("get_project_tags_colors failed for {}: {}", pid, e);
-                    Err(e)
-                }
-            };
+    log::info!(
+        "Fetching issue stats for view '{}' ({} projects)",
+        view.name,
+        projects.len()
+    );
 
-            let members_res = match client.get_memberships(&token, pid).await {
-                Ok(m) => Ok(m),
-                Err(e) => {
-                    log::error!("get_memberships failed for {}: {}", pid, e);
-                    Err(e)
-                }
-            };
+    let mut tasks = Vec::new();
+    for project in projects {
+        tasks.push(tauri::async_runtime::spawn(async move {
+            let (project_id, stats) = project
+                .client
+                .call(|client, token| fetch(client, token, project.project_id))
+                .await?;
+            Ok::<_, crate::error::Error>((project.account_id, project_id, stats))
+        }));
+    }
 
-            let dto = match project_res {
-                Ok(p) => p,
-                Err(e) => {
-                    log::error!("Failed to fetch project {} metadata: {}", pid, e);
-                    return None;
-                }
-            };
+    let mut all_stats = Vec::new();
+    for task in tasks {
+        match task.await {
+            Ok(Ok(stats)) => all_stats.push(stats),
+            Ok(Err(e)) => log::error!("Failed to fetch issue stats: {}", e),
+            Err(e) => log::error!("Task join error: {}", e),
+        }
+    }
 
-            let statuses: Vec<IssueStatus> = dto
-                .issue_statuses
-                .unwrap_or_default()
-                .into_iter()
-                .map(|s| IssueStatus {
-                    id: s.id,
-                    name: s.name,
-                    color: s.color,
-                    is_closed: s.is_closed,
-                })
-                .collect();
+    let today = chrono::Local::now().date_naive();
+    Ok(IssueStatsDashboard::from_projects(all_stats, today))
+}
 
-            let members: Vec<Member> = members_res
-                .unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch memberships for {}: {}", pid, e);
-                    vec![]
-                })
-                .into_iter()
-                .map(|m| Member {
-                    id: m.id,
-                    user_id: m.user,
-                    full_name: m.full_name.unwrap_or_default(),
-                    role_name: m.role_name,
-                    photo: m.photo,
-                })
-                .collect();
+/// Full-text search over issue subjects and descriptions in every selected project
+/// Covers the same accounts as the dashboard. Hits are loaded as full rows and
+/// sorted like the dashboard, newest change first.
+#[tauri::command]
+pub async fn search_issues(
+    accounts: tauri::State<'_, AccountClients>,
+    repo: tauri::State<'_, crate::repositories::SqliteRepository>,
+    text: String,
+    include_user_stories: Option<bool>,
+) -> Result<Vec<Issue>> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Ok(vec![]);
+    }
 
-            let priorities: Vec<Priority> = priorities_res
-                .unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch priorities for {}: {}", pid, e);
-                    vec![]
-                })
-                .into_iter()
-                .map(|p| Priority {
-                    id: p.id,
-                    name: p.name,
-                    color: p.color,
-                    order: p.order,
-                })
-                .collect();
+    let projects = dashboard_projects(&repo, &accounts, &FilterObject::default()).await?;
+    if projects.is_empty() {
+        return Ok(vec![]);
+    }
+    let project_count = projects.len();
+    let include_user_stories = include_user_stories.unwrap_or(false);
 
-            let severities: Vec<Severity> = severities_res
-                .unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch severities for {}: {}", pid, e);
-                    vec![]
-                })
-                .into_iter()
-                .map(|s| Severity {
-                    id: s.id,
-                    name: s.name,
-                    color: s.color,
-                    order: s.order,
-                })
-                .collect();
+    async fn search(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        text: &str,
+        include_user_stories: bool,
+    ) -> Result<Vec<(SearchItemDto, ItemKind)>> {
+        let results = client.search(&token, project_id, text).await?;
+        let mut hits: Vec<(SearchItemDto, ItemKind)> = results
+            .issues
+            .into_iter()
+            .map(|hit| (hit, ItemKind::Issue))
+            .collect();
+        if include_user_stories {
+            hits.extend(
+                results
+                    .userstories
+                    .into_iter()
+                    .map(|hit| (hit, ItemKind::UserStory)),
+            );
+        }
+        Ok(hits)
+    }
 
-            let issue_types: Vec<IssueType> = types_res
-                .unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch issue types for {}: {}", pid, e);
-                    vec![]
-                })
-                .into_iter()
-                .map(|t| IssueType {
-                    id: t.id,
-                    name: t.name,
-                    color: t.color,
-                    order: t.order,
+    async fn load_hit(
+        client: &TaigaClient,
+        token: Secret<String>,
+        id: i64,
+        kind: ItemKind,
+    ) -> Result<Issue> {
+        Ok(match kind {
+            ItemKind::Issue => client.get_issue(&token, id).await?.into(),
+            ItemKind::UserStory => client.get_user_story(&token, id).await?.into(),
+        })
+    }
+
+    let mut tasks = Vec::new();
+    for project in projects {
+        let text = text.clone();
+        tasks.push(tauri::async_runtime::spawn(async move {
+            let hits = project
+                .client
+                .call(|client, token| {
+                    search(
+                        client,
+                        token,
+                        project.project_id,
+                        &text,
+                        include_user_stories,
+                    )
                 })
-                .collect();
+                .await;
+            (project, hits)
+        }));
+    }
 
-            let tags_colors: Vec<TagColor> = tags_res
+    let mut hits = Vec::new();
+    for task in tasks {
+        match task.await {
+            Ok((project, Ok(project_hits))) => {
+                let project = std::sync::Arc::new(project);
+                hits.extend(
+                    project_hits
+                        .into_iter()
+                        .map(|(hit, kind)| (project.clone(), hit, kind)),
+                );
+            }
+            Ok((_, Err(e))) => log::error!("Failed to search project: {}", e),
+            Err(e) => log::error!("Task join error: {}", e),
+        }
+    }
+
+    // Hits only carry id, subject, status and assignee; fetch the full rows a
+    // few at a time, since a broad query can match hundreds of items
+    let mut items: Vec<Issue> = stream::iter(hits)
+        .map(|(project, hit, kind)| async move {
+            let id = hit.id;
+            let mut item = project
+                .client
+                .call(|client, token| load_hit(client, token, id, kind))
+                .await
                 .unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch tags colors for {}: {}", pid, e);
-                    serde_json::Value::Null
-                })
-                .as_object()
-                .map(|obj| {
-                    obj.iter()
-                        .map(|(name, color)| TagColor {
-                            name: name.clone(),
-                            color: color.as_str().map(|s| s.to_string()),
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            Some(crate::domain::project::ProjectMetadata {
-                id: pid,
-                statuses,
-                members,
-                priorities,
-                severities,
-                issue_types,
-                tags_colors,
-            })
+                    log::warn!("Failed to load search hit {}: {}", id, e);
+                    Issue::from_search_hit(hit, project.project_id, kind)
+                });
+            item.account_id = Some(project.account_id.clone());
+            item
+        })
+        .buffer_unordered(SEARCH_HIT_CONCURRENCY)
+        .collect()
+        .await;
+
+    sort_newest_first(&mut items);
+
+    log::info!(
+        "Search for '{}' matched {} item(s) across {} project(s)",
+        text,
+        items.len(),
+        project_count
+    );
+
+    Ok(items)
+}
+
+/// Statuses, members and other options of each project
+/// `account_id` selects the account of dashboard rows from another instance;
+/// the active account is used when it is omitted.
+#[tauri::command]
+pub async fn get_project_metadata(
+    accounts: tauri::State<'_, AccountClients>,
+    project_ids: Vec<i64>,
+    account_id: Option<String>,
+) -> Result<std::collections::HashMap<i64, crate::domain::project::ProjectMetadata>> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let mut tasks = Vec::new();
+
+    for pid in project_ids {
+        let client = client.clone();
+        tasks.push(tauri::async_runtime::spawn(async move {
+            client
+                .call(|client, token| project_metadata::fetch_project_metadata(client, token, pid))
+                .await
+                .ok()
         }));
     }
 
@@ -413,17 +695,16 @@ pub async fn get_project_metadata(
     Ok(result)
 }
 
+/// Dashboard projects of the active account
 #[tauri::command]
 pub async fn get_selected_projects(
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
 ) -> Result<Vec<i64>> {
-    let value_opt = repo.get_config("selected_projects").await?;
-    if let Some(value) = value_opt {
-        let ids: Vec<i64> = serde_json::from_str(&value).unwrap_or_default();
-        Ok(ids)
-    } else {
-        Ok(vec![])
-    }
+    let store = AccountStore::load(repo.inner()).await?;
+    Ok(store
+        .active_account()
+        .map(|account| account.selected_projects.clone())
+        .unwrap_or_default())
 }
 
 #[tauri::command]
@@ -431,18 +712,24 @@ pub async fn save_selected_projects(
     repo: tauri::State<'_, crate::repositories::SqliteRepository>,
     project_ids: Vec<i64>,
 ) -> Result<()> {
-    let value = serde_json::to_string(&project_ids).unwrap_or_default();
-    repo.save_config("selected_projects", &value).await?;
-    Ok(())
+    let mut store = AccountStore::load(repo.inner()).await?;
+    store.active_account_mut()?.selected_projects = project_ids;
+    store.save(repo.inner()).await
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
+    use super::{
+        apply_project_filter, issue_query_params, user_story_query_params, user_story_status_param,
+        AccountScope, FilterObject, ScopedFilter,
+    };
     use taiga_client::models::{IssueDto, IssueStatusExtraInfo};
 
     fn create_issue_dto(id: i64, modified_date: Option<&str>) -> IssueDto {
         IssueDto {
             id,
+            ref_: None,
             subject: format!("Issue {}", id),
             project: 1,
             status: 1,
@@ -458,6 +745,8 @@ mod tests {
             priority: None,
             severity: None,
             type_: None,
+            milestone: None,
+            version: None,
         }
     }
 
@@ -525,4 +814,122 @@ mod tests {
         assert_eq!(issues[2].id, 3); // 2024-01-03
         assert_eq!(issues[3].id, 2); // 2024-01-01 (oldest)
     }
+
+    #[test]
+    fn test_user_story_query_keeps_shared_filters() {
+        let params = [
+            ("exclude_status".to_string(), "4".to_string()),
+            ("assigned_to".to_string(), "null,7".to_string()),
+            ("exclude_severity".to_string(), "2".to_string()),
+        ];
+
+        assert_eq!(user_story_query_params(false, &params), None);
+        // Issue statuses do not apply to stories
+        assert_eq!(
+            user_story_query_params(true, &params),
+            Some(vec![("assigned_to".to_string(), "null,7".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_user_story_status_param_uses_story_statuses() {
+        let filters: FilterObject = serde_json::from_value(serde_json::json!({
+            "status_ids": [1],
+            "user_story_status_ids": [21, 22],
+            "user_story_status_exclude": true
+        }))
+        .unwrap();
+        let scope = AccountScope {
+            account_id: "a",
+            is_active: true,
+        };
+        assert_eq!(
+            user_story_status_param(&filters, &scope),
+            ScopedFilter::Param("exclude_status".to_string(), "21,22".to_string())
+        );
+
+        let filters: FilterObject =
+            serde_json::from_value(serde_json::json!({ "status_ids": [1] })).unwrap();
+        assert_eq!(
+            user_story_status_param(&filters, &scope),
+            ScopedFilter::Unset
+        );
+    }
+
+    #[test]
+    fn test_filter_ids_apply_to_their_own_account() {
+        let filters: FilterObject = serde_json::from_value(serde_json::json!({
+            "status_ids": [
+                { "account_id": "a", "id": 1 },
+                { "account_id": "b", "id": 11 }
+            ],
+            "assignee_ids": [-1, { "account_id": "b", "id": 7 }],
+            "priority_ids": [{ "account_id": "a", "id": 3 }],
+            "priority_exclude": true,
+            "project_ids": [{ "account_id": "b", "id": 5 }, 6]
+        }))
+        .unwrap();
+        let a = AccountScope {
+            account_id: "a",
+            is_active: true,
+        };
+        let b = AccountScope {
+            account_id: "b",
+            is_active: false,
+        };
+
+        assert_eq!(
+            issue_query_params(&filters, &a),
+            Some(vec![
+                ("status".to_string(), "1".to_string()),
+                ("assigned_to".to_string(), "null".to_string()),
+                ("exclude_priority".to_string(), "3".to_string()),
+            ])
+        );
+        assert_eq!(
+            issue_query_params(&filters, &b),
+            Some(vec![
+                ("status".to_string(), "11".to_string()),
+                ("assigned_to".to_string(), "null,7".to_string()),
+            ])
+        );
+        // Plain IDs belong to the active account
+        assert_eq!(apply_project_filter(vec![5, 6], &filters, &a), vec![6]);
+        assert_eq!(apply_project_filter(vec![5, 6], &filters, &b), vec![5]);
+    }
+
+    #[test]
+    fn test_include_filter_of_another_account_matches_nothing() {
+        let filters: FilterObject = serde_json::from_value(serde_json::json!({
+            "status_ids": [{ "account_id": "a", "id": 1 }],
+            "milestone_id": { "account_id": "a", "id": 9 }
+        }))
+        .unwrap();
+        let b = AccountScope {
+            account_id: "b",
+            is_active: false,
+        };
+
+        assert_eq!(issue_query_params(&filters, &b), None);
+    }
+
+    #[test]
+    fn test_user_story_query_skipped_for_issue_only_include_filters() {
+        let params = [
+            ("status".to_string(), "1".to_string()),
+            ("priority".to_string(), "3".to_string()),
+        ];
+
+        assert_eq!(user_story_query_params(true, &params), None);
+    }
+
+    #[test]
+    fn test_user_story_query_keeps_milestone() {
+        let params = [("milestone".to_string(), "11".to_string())];
+
+        assert_eq!(
+            user_story_query_params(true, &params),
+            Some(vec![("milestone".to_string(), "11".to_string())])
+        );
+    }
 }
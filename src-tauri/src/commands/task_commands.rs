@@ -0,0 +1,228 @@
+use crate::domain::issue_detail::HistoryEntry;
+use crate::domain::task::{Task, TaskDetail};
+use crate::error::Result;
+use crate::services::accounts::AccountClients;
+use crate::services::auth_client::AuthClient;
+use secrecy::Secret;
+use serde::Deserialize;
+use taiga_client::models::{CreateTaskRequest, PatchTaskRequest};
+use taiga_client::TaigaClient;
+
+/// Fields for a new task; `user_story_id` attaches it to a story
+#[derive(Debug, Deserialize)]
+pub struct NewTask {
+    pub project_id: i64,
+    pub subject: String,
+    pub user_story_id: Option<i64>,
+    pub description: Option<String>,
+    pub status_id: Option<i64>,
+    pub assignee_id: Option<i64>,
+}
+
+/// List the tasks of a project, or only those of one user story
+#[tauri::command]
+pub async fn list_tasks(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    user_story_id: Option<i64>,
+    account_id: Option<String>,
+) -> Result<Vec<Task>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        user_story_id: Option<i64>,
+    ) -> Result<Vec<Task>> {
+        let filters = user_story_id.map(|id| vec![("user_story".to_string(), id.to_string())]);
+        let tasks_dto = client.list_tasks(&token, project_id, filters).await?;
+        Ok(tasks_dto.into_iter().map(|t| t.into()).collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, project_id, user_story_id))
+        .await
+}
+
+/// Get detailed task information by ID
+#[tauri::command]
+pub async fn get_task_detail(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        task_id: i64,
+    ) -> Result<TaskDetail> {
+        let task_dto = client.get_task(&token, task_id).await?;
+        Ok(TaskDetail::from_dto(task_dto))
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, task_id))
+        .await
+}
+
+/// Get task history (comments and changes)
+#[tauri::command]
+pub async fn get_task_history(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    account_id: Option<String>,
+) -> Result<Vec<HistoryEntry>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        task_id: i64,
+    ) -> Result<Vec<HistoryEntry>> {
+        let history_dto = client.get_task_history(&token, task_id).await?;
+        Ok(history_dto
+            .iter()
+            .filter(|h| !h.is_hidden.unwrap_or(false))
+            .map(|h| h.into())
+            .collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, task_id))
+        .await
+}
+
+/// Create a new task, optionally under a user story
+#[tauri::command]
+pub async fn create_task(
+    accounts: tauri::State<'_, AccountClients>,
+    task: NewTask,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let subject = task.subject.trim().to_string();
+    if subject.is_empty() {
+        return Err(crate::error::Error::InvalidInput(
+            "Task subject cannot be empty".to_string(),
+        ));
+    }
+
+    let request = CreateTaskRequest {
+        project: task.project_id,
+        subject,
+        user_story: task.user_story_id,
+        description: task.description,
+        status: task.status_id,
+        assigned_to: task.assignee_id,
+    };
+
+    async fn create(
+        client: &TaigaClient,
+        token: Secret<String>,
+        request: &CreateTaskRequest,
+    ) -> Result<TaskDetail> {
+        let created = client.create_task(&token, request.clone()).await?;
+        Ok(TaskDetail::from_dto(created))
+    }
+
+    client
+        .call(|client, token| create(client, token, &request))
+        .await
+}
+
+/// Send a task patch, refreshing the token once if needed
+async fn patch_task(
+    client: &AuthClient,
+    task_id: i64,
+    request: PatchTaskRequest,
+) -> Result<TaskDetail> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        task_id: i64,
+        request: &PatchTaskRequest,
+    ) -> Result<TaskDetail> {
+        let updated_dto = client.patch_task(&token, task_id, request.clone()).await?;
+        Ok(TaskDetail::from_dto(updated_dto))
+    }
+
+    client
+        .call(|client, token| fetch(client, token, task_id, &request))
+        .await
+}
+
+/// Change the status of a task
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn change_task_status(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    status_id: i64,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchTaskRequest {
+        version,
+        status: Some(status_id),
+        ..Default::default()
+    };
+    patch_task(&client, task_id, request).await
+}
+
+/// Change the assignee of a task
+/// Pass `None` for assignee_id to unassign
+#[tauri::command]
+pub async fn change_task_assignee(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    assignee_id: Option<i64>,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchTaskRequest {
+        version,
+        assigned_to: Some(assignee_id),
+        ..Default::default()
+    };
+    patch_task(&client, task_id, request).await
+}
+
+/// Move a task to another user story of the same project
+/// Pass `None` for user_story_id to detach it from its story
+#[tauri::command]
+pub async fn move_task_to_user_story(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    user_story_id: Option<i64>,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchTaskRequest {
+        version,
+        user_story: Some(user_story_id),
+        ..Default::default()
+    };
+    patch_task(&client, task_id, request).await
+}
+
+/// Add a comment to a task
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn add_task_comment(
+    accounts: tauri::State<'_, AccountClients>,
+    task_id: i64,
+    comment: String,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<TaskDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchTaskRequest {
+        version,
+        comment: Some(comment),
+        ..Default::default()
+    };
+    patch_task(&client, task_id, request).await
+}
@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::domain::issue_detail::Attachment;
+use crate::error::Result;
+use crate::repositories::{Repository, SqliteRepository};
+use crate::services::accounts::AccountClients;
+use crate::services::transfers::{
+    parse_max_upload_size, ProgressThrottle, TransferProgressEvent, TransferRegistry,
+    MAX_UPLOAD_SIZE_KEY, TRANSFER_PROGRESS_EVENT,
+};
+use secrecy::Secret;
+use serde::Deserialize;
+use taiga_client::transfer::AttachmentUpload;
+use taiga_client::TaigaClient;
+use tauri::Emitter;
+
+/// A file on disk to attach to an existing issue
+#[derive(Debug, Deserialize)]
+pub struct FileAttachment {
+    pub project_id: i64,
+    pub issue_id: i64,
+    /// Absolute path of the file to upload
+    pub file_path: String,
+    pub mime_type: Option<String>,
+}
+
+/// Download an attachment to a file chosen by the user
+/// Progress is emitted as `transfer-progress` events tagged with `transfer_id`;
+/// `cancel_transfer` with the same ID stops the download and removes the partial file.
+#[tauri::command]
+pub async fn download_attachment(
+    app: tauri::AppHandle,
+    accounts: tauri::State<'_, AccountClients>,
+    registry: tauri::State<'_, TransferRegistry>,
+    transfer_id: String,
+    url: String,
+    destination: String,
+    account_id: Option<String>,
+) -> Result<u64> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let destination = PathBuf::from(destination);
+    if !destination.is_absolute() {
+        return Err(crate::error::Error::InvalidInput(
+            "Download destination must be an absolute path".to_string(),
+        ));
+    }
+
+    let cancel = registry.register(&transfer_id)?;
+
+    async fn fetch(
+        app: &tauri::AppHandle,
+        client: &TaigaClient,
+        token: Secret<String>,
+        transfer_id: &str,
+        url: &str,
+        destination: &std::path::Path,
+        cancel: &std::sync::atomic::AtomicBool,
+    ) -> Result<u64> {
+        let mut throttle = ProgressThrottle::default();
+        let written = client
+            .download_to_path(url, Some(&token), destination, cancel, |progress| {
+                if !throttle.should_emit(&progress, Instant::now()) {
+                    return;
+                }
+                let event = TransferProgressEvent {
+                    transfer_id: transfer_id.to_string(),
+                    transferred: progress.transferred,
+                    total: progress.total,
+                };
+                if let Err(e) = app.emit(TRANSFER_PROGRESS_EVENT, event) {
+                    log::warn!("Failed to emit transfer progress: {}", e);
+                }
+            })
+            .await?;
+        Ok(written)
+    }
+
+    log::info!(
+        "Downloading attachment to {} (transfer {})",
+        destination.display(),
+        transfer_id
+    );
+
+    let result = client
+        .call(|client, token| {
+            fetch(
+                &app,
+                client,
+                token,
+                &transfer_id,
+                &url,
+                &destination,
+                &cancel,
+            )
+        })
+        .await;
+
+    registry.finish(&transfer_id);
+    result
+}
+
+/// Attach a local file to an issue, streaming it from disk instead of over IPC
+/// Files over the configured upload limit are refused before anything is sent.
+/// Progress and cancellation work like `download_attachment`.
+#[tauri::command]
+pub async fn upload_attachment_from_path(
+    app: tauri::AppHandle,
+    accounts: tauri::State<'_, AccountClients>,
+    repository: tauri::State<'_, SqliteRepository>,
+    registry: tauri::State<'_, TransferRegistry>,
+    transfer_id: String,
+    attachment: FileAttachment,
+    account_id: Option<String>,
+) -> Result<Attachment> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let path = PathBuf::from(attachment.file_path);
+    if !path.is_absolute() {
+        return Err(crate::error::Error::InvalidInput(
+            "Upload source must be an absolute path".to_string(),
+        ));
+    }
+
+    let max_size =
+        parse_max_upload_size(repository.get_config(MAX_UPLOAD_SIZE_KEY).await?.as_deref());
+    let upload = AttachmentUpload {
+        project_id: attachment.project_id,
+        issue_id: attachment.issue_id,
+        path,
+        mime_type: attachment.mime_type,
+        max_size: Some(max_size),
+    };
+    let cancel = registry.register(&transfer_id)?;
+
+    async fn fetch(
+        app: &tauri::AppHandle,
+        client: &TaigaClient,
+        token: Secret<String>,
+        transfer_id: &str,
+        upload: &AttachmentUpload,
+        cancel: &Arc<AtomicBool>,
+    ) -> Result<Attachment> {
+        let app = app.clone();
+        let transfer_id = transfer_id.to_string();
+        let mut throttle = ProgressThrottle::default();
+        let attachment_dto = client
+            .upload_attachment_from_path(&token, upload, Arc::clone(cancel), move |progress| {
+                if !throttle.should_emit(&progress, Instant::now()) {
+                    return;
+                }
+                let event = TransferProgressEvent {
+                    transfer_id: transfer_id.clone(),
+                    transferred: progress.transferred,
+                    total: progress.total,
+                };
+                if let Err(e) = app.emit(TRANSFER_PROGRESS_EVENT, event) {
+                    log::warn!("Failed to emit transfer progress: {}", e);
+                }
+            })
+            .await?;
+        Ok((&attachment_dto).into())
+    }
+
+    log::info!(
+        "Uploading {} to issue {} (transfer {}, limit {} bytes)",
+        upload.path.display(),
+        upload.issue_id,
+        transfer_id,
+        max_size
+    );
+
+    let result = client
+        .call(|client, token| fetch(&app, client, token, &transfer_id, &upload, &cancel))
+        .await;
+
+    registry.finish(&transfer_id);
+    result
+}
+
+/// Largest file `upload_attachment_from_path` accepts, in bytes
+#[tauri::command]
+pub async fn get_max_upload_size(repository: tauri::State<'_, SqliteRepository>) -> Result<u64> {
+    let value = repository.get_config(MAX_UPLOAD_SIZE_KEY).await?;
+    Ok(parse_max_upload_size(value.as_deref()))
+}
+
+#[tauri::command]
+pub async fn set_max_upload_size(
+    repository: tauri::State<'_, SqliteRepository>,
+    bytes: u64,
+) -> Result<()> {
+    if bytes == 0 {
+        return Err(crate::error::Error::InvalidInput(
+            "Upload limit must be greater than zero".to_string(),
+        ));
+    }
+    repository
+        .save_config(MAX_UPLOAD_SIZE_KEY, &bytes.to_string())
+        .await
+}
+
+/// Stop a running download or upload
+/// Returns false when the transfer already finished or never started.
+#[tauri::command]
+pub async fn cancel_transfer(
+    registry: tauri::State<'_, TransferRegistry>,
+    transfer_id: String,
+) -> Result<bool> {
+    log::info!("Cancelling transfer {}", transfer_id);
+    Ok(registry.cancel(&transfer_id))
+}
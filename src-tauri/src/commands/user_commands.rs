@@ -1,11 +1,16 @@
 use crate::domain::user::User;
 use crate::error::Result;
-use crate::services::credentials;
+use crate::services::accounts::AccountClients;
+use secrecy::Secret;
 use taiga_client::TaigaClient;
 
 #[tauri::command]
-pub async fn get_me(client: tauri::State<'_, TaigaClient>) -> Result<User> {
-    let token = credentials::get_api_token()?;
-    let user = client.get_me(&token).await?;
-    Ok(user.into())
+pub async fn get_me(accounts: tauri::State<'_, AccountClients>) -> Result<User> {
+    async fn fetch(client: &TaigaClient, token: Secret<String>) -> Result<User> {
+        let user = client.get_me(&token).await?;
+        Ok(user.into())
+    }
+
+    let client = accounts.active()?;
+    client.call(fetch).await
 }
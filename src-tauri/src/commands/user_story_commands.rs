@@ -0,0 +1,243 @@
+use crate::domain::issue::Issue;
+use crate::domain::issue_detail::HistoryEntry;
+use crate::domain::user_story::UserStoryDetail;
+use crate::error::Result;
+use crate::repositories::{Repository, SqliteRepository};
+use crate::services::accounts::AccountClients;
+use crate::services::auth_client::AuthClient;
+use secrecy::Secret;
+use taiga_client::models::PatchUserStoryRequest;
+use taiga_client::TaigaClient;
+
+/// List the user stories of a project as dashboard rows
+#[tauri::command]
+pub async fn list_user_stories(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    account_id: Option<String>,
+) -> Result<Vec<Issue>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<Issue>> {
+        let stories_dto = client.list_user_stories(&token, project_id, None).await?;
+        Ok(stories_dto.into_iter().map(|s| s.into()).collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, project_id))
+        .await
+}
+
+/// Get detailed user story information by ID
+#[tauri::command]
+pub async fn get_user_story_detail(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        story_id: i64,
+    ) -> Result<UserStoryDetail> {
+        let story_dto = client.get_user_story(&token, story_id).await?;
+        Ok(UserStoryDetail::from_dto(story_dto))
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, story_id))
+        .await
+}
+
+/// Get user story history (comments and changes)
+#[tauri::command]
+pub async fn get_user_story_history(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    account_id: Option<String>,
+) -> Result<Vec<HistoryEntry>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        story_id: i64,
+    ) -> Result<Vec<HistoryEntry>> {
+        let history_dto = client.get_user_story_history(&token, story_id).await?;
+        Ok(history_dto
+            .iter()
+            .filter(|h| !h.is_hidden.unwrap_or(false))
+            .map(|h| h.into())
+            .collect())
+    }
+
+    let client = accounts.resolve(account_id.as_deref())?;
+    client
+        .call(|client, token| fetch(client, token, story_id))
+        .await
+}
+
+/// Send a user story patch, refreshing the token once if needed
+async fn patch_user_story(
+    client: &AuthClient,
+    story_id: i64,
+    request: PatchUserStoryRequest,
+) -> Result<UserStoryDetail> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        story_id: i64,
+        request: &PatchUserStoryRequest,
+    ) -> Result<UserStoryDetail> {
+        let updated_dto = client
+            .patch_user_story(&token, story_id, request.clone())
+            .await?;
+        Ok(UserStoryDetail::from_dto(updated_dto))
+    }
+
+    client
+        .call(|client, token| fetch(client, token, story_id, &request))
+        .await
+}
+
+/// Change the status of a user story
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn change_user_story_status(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    status_id: i64,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchUserStoryRequest {
+        version,
+        status: Some(status_id),
+        ..Default::default()
+    };
+    patch_user_story(&client, story_id, request).await
+}
+
+/// Change the subject (title) of a user story
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn change_user_story_subject(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    subject: String,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchUserStoryRequest {
+        version,
+        subject: Some(subject),
+        ..Default::default()
+    };
+    patch_user_story(&client, story_id, request).await
+}
+
+/// Change the assignee of a user story
+/// Pass `None` for assignee_id to unassign
+#[tauri::command]
+pub async fn change_user_story_assignee(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    assignee_id: Option<i64>,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchUserStoryRequest {
+        version,
+        assigned_to: Some(assignee_id),
+        ..Default::default()
+    };
+    patch_user_story(&client, story_id, request).await
+}
+
+/// Add a comment to a user story
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn add_user_story_comment(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    comment: String,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let request = PatchUserStoryRequest {
+        version,
+        comment: Some(comment),
+        ..Default::default()
+    };
+    patch_user_story(&client, story_id, request).await
+}
+
+#[tauri::command]
+pub async fn update_user_story_tags(
+    accounts: tauri::State<'_, AccountClients>,
+    story_id: i64,
+    tags: Vec<(String, Option<String>)>,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let tags_json: Vec<serde_json::Value> = tags
+        .iter()
+        .map(|(name, color)| serde_json::json!([name, color]))
+        .collect();
+
+    let request = PatchUserStoryRequest {
+        version,
+        tags: Some(serde_json::Value::Array(tags_json)),
+        ..Default::default()
+    };
+    patch_user_story(&client, story_id, request).await
+}
+
+/// Commit a description change from local draft to Taiga API
+/// Drafts for stories are stored under `user_story_{id}`; deleted on success
+#[tauri::command]
+pub async fn commit_user_story_description(
+    accounts: tauri::State<'_, AccountClients>,
+    repository: tauri::State<'_, SqliteRepository>,
+    story_id: i64,
+    version: i64,
+    account_id: Option<String>,
+) -> Result<UserStoryDetail> {
+    let client = accounts.resolve(account_id.as_deref())?;
+    let related_id = format!("user_story_{}", story_id);
+    let draft_type = "description";
+
+    let description = repository
+        .get_draft(&related_id, draft_type)
+        .await?
+        .ok_or_else(|| crate::error::Error::Database("No draft found to commit".to_string()))?;
+
+    log::info!(
+        "Committing description draft for user story {} (version {})",
+        story_id,
+        version
+    );
+
+    let request = PatchUserStoryRequest {
+        version,
+        description: Some(description),
+        ..Default::default()
+    };
+    let result = patch_user_story(&client, story_id, request).await?;
+
+    repository.delete_draft(&related_id, draft_type).await?;
+
+    log::info!(
+        "Successfully committed description for user story {}",
+        story_id
+    );
+
+    Ok(result)
+}
@@ -76,10 +76,7 @@ pub async fn update_view(
 /// Deletes a saved view by ID.
 /// System views cannot be deleted (enforced by repository layer).
 #[tauri::command]
-pub async fn delete_view(
-    id: i32,
-    repository: tauri::State<'_, SqliteRepository>,
-) -> Result<()> {
+pub async fn delete_view(id: i32, repository: tauri::State<'_, SqliteRepository>) -> Result<()> {
     repository.delete_view(id).await.map_err(|e| {
         log::error!("Failed to delete view {}: {}", id, e);
         e
@@ -0,0 +1,179 @@
+use crate::domain::wiki::{WikiLink, WikiPage};
+use crate::error::Result;
+use crate::repositories::{Repository, SqliteRepository};
+use crate::services::accounts::AccountClients;
+use secrecy::Secret;
+use taiga_client::models::{CreateWikiPageRequest, PatchWikiPageRequest};
+use taiga_client::TaigaClient;
+
+/// List the wiki pages of a project
+#[tauri::command]
+pub async fn list_wiki_pages(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+) -> Result<Vec<WikiPage>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<WikiPage>> {
+        let pages_dto = client.list_wiki_pages(&token, project_id).await?;
+        let mut pages: Vec<WikiPage> = pages_dto.into_iter().map(|p| p.into()).collect();
+        pages.sort_by(|a, b| a.slug.cmp(&b.slug));
+        Ok(pages)
+    }
+
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, project_id))
+        .await
+}
+
+/// List the wiki sidebar links of a project, in their configured order
+#[tauri::command]
+pub async fn list_wiki_links(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+) -> Result<Vec<WikiLink>> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+    ) -> Result<Vec<WikiLink>> {
+        let links_dto = client.list_wiki_links(&token, project_id).await?;
+        let mut links: Vec<WikiLink> = links_dto.into_iter().map(|l| l.into()).collect();
+        links.sort_by_key(|l| l.order);
+        Ok(links)
+    }
+
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, project_id))
+        .await
+}
+
+/// Get a wiki page by ID
+#[tauri::command]
+pub async fn get_wiki_page(
+    accounts: tauri::State<'_, AccountClients>,
+    page_id: i64,
+) -> Result<WikiPage> {
+    async fn fetch(client: &TaigaClient, token: Secret<String>, page_id: i64) -> Result<WikiPage> {
+        let page_dto = client.get_wiki_page(&token, page_id).await?;
+        Ok(page_dto.into())
+    }
+
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, page_id))
+        .await
+}
+
+/// Get a wiki page by slug, as referenced by wiki links
+#[tauri::command]
+pub async fn get_wiki_page_by_slug(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    slug: String,
+) -> Result<WikiPage> {
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        project_id: i64,
+        slug: &str,
+    ) -> Result<WikiPage> {
+        let page_dto = client
+            .get_wiki_page_by_slug(&token, project_id, slug)
+            .await?;
+        Ok(page_dto.into())
+    }
+
+    let client = accounts.active()?;
+    client
+        .call(|client, token| fetch(client, token, project_id, &slug))
+        .await
+}
+
+/// Create a new wiki page in a project
+#[tauri::command]
+pub async fn create_wiki_page(
+    accounts: tauri::State<'_, AccountClients>,
+    project_id: i64,
+    slug: String,
+    content: String,
+) -> Result<WikiPage> {
+    let client = accounts.active()?;
+    if slug.trim().is_empty() {
+        return Err(crate::error::Error::InvalidInput(
+            "Wiki page slug cannot be empty".to_string(),
+        ));
+    }
+
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        request: &CreateWikiPageRequest,
+    ) -> Result<WikiPage> {
+        let page_dto = client.create_wiki_page(&token, request.clone()).await?;
+        Ok(page_dto.into())
+    }
+
+    let request = CreateWikiPageRequest {
+        project: project_id,
+        slug: slug.trim().to_string(),
+        content,
+    };
+
+    client
+        .call(|client, token| fetch(client, token, &request))
+        .await
+}
+
+/// Commit a wiki page edit from local draft to Taiga API
+/// Drafts for wiki pages are stored under `wiki_{id}`; deleted on success
+/// Uses optimistic locking via the version field
+#[tauri::command]
+pub async fn commit_wiki_page(
+    accounts: tauri::State<'_, AccountClients>,
+    repository: tauri::State<'_, SqliteRepository>,
+    page_id: i64,
+    version: i64,
+) -> Result<WikiPage> {
+    let client = accounts.active()?;
+    let related_id = format!("wiki_{}", page_id);
+    let draft_type = "content";
+
+    let content = repository
+        .get_draft(&related_id, draft_type)
+        .await?
+        .ok_or_else(|| crate::error::Error::Database("No draft found to commit".to_string()))?;
+
+    log::info!(
+        "Committing content draft for wiki page {} (version {})",
+        page_id,
+        version
+    );
+
+    async fn fetch(
+        client: &TaigaClient,
+        token: Secret<String>,
+        page_id: i64,
+        request: &PatchWikiPageRequest,
+    ) -> Result<WikiPage> {
+        let page_dto = client
+            .patch_wiki_page(&token, page_id, request.clone())
+            .await?;
+        Ok(page_dto.into())
+    }
+
+    let request = PatchWikiPageRequest { version, content };
+    let result = client
+        .call(|client, token| fetch(client, token, page_id, &request))
+        .await?;
+
+    repository.delete_draft(&related_id, draft_type).await?;
+
+    log::info!("Successfully committed content for wiki page {}", page_id);
+
+    Ok(result)
+}
@@ -0,0 +1,178 @@
+use serde::Serialize;
+use taiga_client::models::{CustomAttributeDto, IssueCustomAttributesValuesDto};
+
+// ============================================================================
+// Custom Attribute Domain Models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomAttribute {
+    pub id: i64,
+    pub name: String,
+    pub description: String,
+    /// "text", "multiline", "richtext", "date", "url", "dropdown", "checkbox" or "number"
+    pub field_type: String,
+    pub order: i64,
+    /// Allowed values for dropdown attributes
+    pub options: Vec<String>,
+}
+
+impl From<&CustomAttributeDto> for CustomAttribute {
+    fn from(dto: &CustomAttributeDto) -> Self {
+        let options = dto
+            .extra
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            id: dto.id,
+            name: dto.name.clone(),
+            description: dto.description.clone(),
+            field_type: dto.type_.clone(),
+            order: dto.order,
+            options,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomAttributeValue {
+    pub attribute_id: i64,
+    pub name: String,
+    pub field_type: String,
+    /// `null` when the attribute has no value on this issue
+    pub value: serde_json::Value,
+}
+
+/// Custom attribute values of an issue, one entry per project attribute
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueCustomAttributes {
+    pub issue_id: i64,
+    pub version: i64,
+    pub values: Vec<CustomAttributeValue>,
+}
+
+impl IssueCustomAttributes {
+    /// Pair each project attribute (in display order) with the issue's value
+    /// Values of attributes that no longer exist are dropped.
+    pub fn from_dtos(
+        attributes: &[CustomAttributeDto],
+        values_dto: IssueCustomAttributesValuesDto,
+    ) -> Self {
+        let mut attributes: Vec<&CustomAttributeDto> = attributes.iter().collect();
+        attributes.sort_by_key(|a| (a.order, a.id));
+
+        let values = attributes
+            .into_iter()
+            .map(|a| CustomAttributeValue {
+                attribute_id: a.id,
+                name: a.name.clone(),
+                field_type: a.type_.clone(),
+                value: values_dto
+                    .attributes_values
+                    .get(&a.id.to_string())
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null),
+            })
+            .collect();
+
+        Self {
+            issue_id: values_dto.issue,
+            version: values_dto.version,
+            values,
+        }
+    }
+}
+
+/// Apply edited values on top of the current ones
+/// Taiga replaces the whole map on PATCH; a `null` change clears the attribute.
+pub fn merge_attribute_values(
+    current: &serde_json::Map<String, serde_json::Value>,
+    changes: &std::collections::HashMap<i64, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut merged = current.clone();
+    for (attribute_id, value) in changes {
+        let key = attribute_id.to_string();
+        if value.is_null() {
+            merged.remove(&key);
+        } else {
+            merged.insert(key, value.clone());
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn attribute(id: i64, name: &str, order: i64) -> CustomAttributeDto {
+        CustomAttributeDto {
+            id,
+            name: name.to_string(),
+            description: String::new(),
+            type_: "text".to_string(),
+            order,
+            project: 1,
+            extra: serde_json::Value::Null,
+        }
+    }
+
+    fn values(map: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+        map.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_from_dtos_orders_attributes_and_fills_missing_values() {
+        let attributes = [
+            attribute(4, "Found in version", 2),
+            attribute(3, "Customer", 1),
+        ];
+        let values_dto = IssueCustomAttributesValuesDto {
+            attributes_values: values(json!({ "3": "ACME", "99": "orphan" })),
+            version: 5,
+            issue: 10,
+        };
+
+        let result = IssueCustomAttributes::from_dtos(&attributes, values_dto);
+
+        assert_eq!(result.version, 5);
+        assert_eq!(result.values.len(), 2);
+        assert_eq!(result.values[0].name, "Customer");
+        assert_eq!(result.values[0].value, json!("ACME"));
+        assert_eq!(result.values[1].value, serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_dropdown_options_from_extra() {
+        let mut dto = attribute(3, "Customer", 1);
+        dto.type_ = "dropdown".to_string();
+        dto.extra = json!(["ACME", "Globex"]);
+
+        let attribute = CustomAttribute::from(&dto);
+        assert_eq!(attribute.options, vec!["ACME", "Globex"]);
+    }
+
+    #[test]
+    fn test_merge_keeps_untouched_values_and_clears_nulls() {
+        let current = values(json!({ "3": "ACME", "4": "1.2.0" }));
+        let changes = std::collections::HashMap::from([
+            (3, json!("Globex")),
+            (4, serde_json::Value::Null),
+            (5, json!(true)),
+        ]);
+
+        let merged = merge_attribute_values(&current, &changes);
+
+        assert_eq!(
+            serde_json::Value::Object(merged),
+            json!({ "3": "Globex", "5": true })
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use serde::Serialize;
+use taiga_client::models::{EpicDto, UserStoryDto};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Epic {
+    pub id: i64,
+    pub ref_number: i64,
+    pub subject: String,
+    pub project: i64,
+    pub color: Option<String>,
+    pub status: i64,
+    pub status_name: Option<String>,
+    pub status_color: Option<String>,
+    pub is_closed: bool,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_name: Option<String>,
+    pub assigned_to_photo: Option<String>,
+    pub modified_date: Option<String>,
+
+    // Progress over the related user stories
+    pub total_user_stories: usize,
+    pub closed_user_stories: usize,
+    /// Share of closed related stories, 0-100
+    pub progress: f64,
+    /// Account the epic was fetched with, set on the aggregated dashboard
+    pub account_id: Option<String>,
+}
+
+impl Epic {
+    /// Build an epic from its DTO and the user stories related to it
+    pub fn from_dto(dto: EpicDto, stories: &[UserStoryDto]) -> Self {
+        let total_user_stories = stories.len();
+        let closed_user_stories = stories.iter().filter(|s| s.is_closed).count();
+        let progress = if total_user_stories == 0 {
+            0.0
+        } else {
+            closed_user_stories as f64 * 100.0 / total_user_stories as f64
+        };
+
+        Self {
+            id: dto.id,
+            ref_number: dto.ref_,
+            subject: dto.subject,
+            project: dto.project,
+            color: dto.color,
+            status: dto.status,
+            status_name: dto.status_extra_info.as_ref().map(|s| s.name.clone()),
+            status_color: dto.status_extra_info.as_ref().map(|s| s.color.clone()),
+            is_closed: dto
+                .status_extra_info
+                .as_ref()
+                .map(|s| s.is_closed)
+                .unwrap_or(dto.is_closed),
+            assigned_to: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|u| u.full_name_display.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|u| u.photo.clone()),
+            modified_date: dto.modified_date,
+            total_user_stories,
+            closed_user_stories,
+            progress,
+            account_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epic_dto() -> EpicDto {
+        EpicDto {
+            id: 5,
+            ref_: 2,
+            subject: "Payments".to_string(),
+            project: 1,
+            color: Some("#aabbcc".to_string()),
+            status: 1,
+            status_extra_info: None,
+            owner: None,
+            assigned_to: None,
+            assigned_to_extra_info: None,
+            modified_date: None,
+            is_closed: false,
+        }
+    }
+
+    fn story(id: i64, is_closed: bool) -> UserStoryDto {
+        UserStoryDto {
+            id,
+            ref_: id,
+            subject: format!("Story {}", id),
+            project: 1,
+            status: 1,
+            status_extra_info: None,
+            owner: None,
+            assigned_to: None,
+            assigned_to_extra_info: None,
+            modified_date: None,
+            total_points: None,
+            milestone: None,
+            is_closed,
+            is_blocked: false,
+        }
+    }
+
+    #[test]
+    fn test_epic_progress_from_related_stories() {
+        let stories = [
+            story(1, true),
+            story(2, false),
+            story(3, true),
+            story(4, false),
+        ];
+        let epic = Epic::from_dto(epic_dto(), &stories);
+
+        assert_eq!(epic.total_user_stories, 4);
+        assert_eq!(epic.closed_user_stories, 2);
+        assert_eq!(epic.progress, 50.0);
+    }
+
+    #[test]
+    fn test_epic_without_stories_has_no_progress() {
+        let epic = Epic::from_dto(epic_dto(), &[]);
+
+        assert_eq!(epic.total_user_stories, 0);
+        assert_eq!(epic.progress, 0.0);
+    }
+}
@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+use taiga_client::models::{FilterOptionDto, FilterTagDto, FilterUserDto, IssueFiltersDataDto};
+
+// ============================================================================
+// Filter Facet Domain Models
+// ============================================================================
+
+/// An ID together with the account whose Taiga instance numbered it
+/// Statuses, users, projects and sprints are numbered per instance, so the same
+/// number can mean different things on two accounts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopedId {
+    pub account_id: String,
+    pub id: i64,
+}
+
+/// A filter ID as the UI sends it
+/// Plain numbers come from views saved before IDs carried their account; they
+/// belong to the active account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FilterId {
+    Scoped(ScopedId),
+    Plain(i64),
+}
+
+/// Status, type, priority or severity option summed across projects
+/// Each project has its own IDs, so options with the same name are grouped.
+#[derive(Debug, Clone, Serialize)]
+pub struct FacetOption {
+    pub name: String,
+    pub color: Option<String>,
+    /// IDs of this option in every project that has it
+    pub ids: Vec<ScopedId>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserFacet {
+    /// Account the user belongs to; `None` for unassigned, which spans every account
+    pub account_id: Option<String>,
+    /// `None` counts unassigned issues
+    pub user_id: Option<i64>,
+    pub name: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagFacet {
+    pub name: String,
+    pub color: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueFacets {
+    pub statuses: Vec<FacetOption>,
+    pub types: Vec<FacetOption>,
+    pub priorities: Vec<FacetOption>,
+    pub severities: Vec<FacetOption>,
+    pub assignees: Vec<UserFacet>,
+    pub owners: Vec<UserFacet>,
+    pub tags: Vec<TagFacet>,
+}
+
+impl IssueFacets {
+    /// Sum the facets of several projects, each with its account, into one filter panel
+    pub fn merge(projects: Vec<(String, IssueFiltersDataDto)>) -> Self {
+        let mut statuses = Vec::new();
+        let mut types = Vec::new();
+        let mut priorities = Vec::new();
+        let mut severities = Vec::new();
+        let mut assignees = Vec::new();
+        let mut owners = Vec::new();
+        let mut tags = Vec::new();
+
+        for (account_id, project) in projects {
+            statuses.extend(with_account(&account_id, project.statuses));
+            types.extend(with_account(&account_id, project.types));
+            priorities.extend(with_account(&account_id, project.priorities));
+            severities.extend(with_account(&account_id, project.severities));
+            assignees.extend(with_account(&account_id, project.assigned_to));
+            owners.extend(with_account(&account_id, project.owners));
+            tags.extend(project.tags);
+        }
+
+        Self {
+            statuses: merge_options(statuses),
+            types: merge_options(types),
+            priorities: merge_options(priorities),
+            severities: merge_options(severities),
+            assignees: merge_users(assignees),
+            owners: merge_users(owners),
+            tags: merge_tags(tags),
+        }
+    }
+}
+
+fn with_account<'a, T: 'a>(
+    account_id: &'a str,
+    items: Vec<T>,
+) -> impl Iterator<Item = (String, T)> + 'a {
+    items
+        .into_iter()
+        .map(move |item| (account_id.to_string(), item))
+}
+
+/// Group options by case-insensitive name, keeping the lowest `order` for sorting
+fn merge_options(options: Vec<(String, FilterOptionDto)>) -> Vec<FacetOption> {
+    let mut groups: Vec<(i64, FacetOption)> = Vec::new();
+
+    for (account_id, option) in options {
+        let id = ScopedId {
+            account_id,
+            id: option.id,
+        };
+        let key = option.name.trim().to_lowercase();
+        match groups
+            .iter_mut()
+            .find(|(_, g)| g.name.trim().to_lowercase() == key)
+        {
+            Some((order, group)) => {
+                *order = (*order).min(option.order);
+                group.count += option.count;
+                if !group.ids.contains(&id) {
+                    group.ids.push(id);
+                }
+            }
+            None => groups.push((
+                option.order,
+                FacetOption {
+                    name: option.name,
+                    color: option.color,
+                    ids: vec![id],
+                    count: option.count,
+                },
+            )),
+        }
+    }
+
+    groups.sort_by(|(a_order, a), (b_order, b)| a_order.cmp(b_order).then(a.name.cmp(&b.name)));
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Group users by account and ID; unassigned comes first, then by name
+fn merge_users(users: Vec<(String, FilterUserDto)>) -> Vec<UserFacet> {
+    let mut merged: Vec<UserFacet> = Vec::new();
+
+    for (account_id, user) in users {
+        let account_id = user.id.map(|_| account_id);
+        match merged
+            .iter_mut()
+            .find(|u| u.user_id == user.id && u.account_id == account_id)
+        {
+            Some(existing) => {
+                existing.count += user.count;
+                if existing.name.is_empty() {
+                    existing.name = user.full_name;
+                }
+            }
+            None => merged.push(UserFacet {
+                account_id,
+                user_id: user.id,
+                name: user.full_name,
+                count: user.count,
+            }),
+        }
+    }
+
+    merged.sort_by(|a, b| {
+        a.user_id
+            .is_some()
+            .cmp(&b.user_id.is_some())
+            .then(a.name.cmp(&b.name))
+    });
+    merged
+}
+
+fn merge_tags(tags: Vec<FilterTagDto>) -> Vec<TagFacet> {
+    let mut merged: Vec<TagFacet> = Vec::new();
+
+    for tag in tags {
+        match merged.iter_mut().find(|t| t.name == tag.name) {
+            Some(existing) => {
+                existing.count += tag.count;
+                if existing.color.is_none() {
+                    existing.color = tag.color;
+                }
+            }
+            None => merged.push(TagFacet {
+                name: tag.name,
+                color: tag.color,
+                count: tag.count,
+            }),
+        }
+    }
+
+    merged.sort_by(|a, b| a.name.cmp(&b.name));
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(id: i64, name: &str, order: i64, count: i64) -> FilterOptionDto {
+        FilterOptionDto {
+            id,
+            name: name.to_string(),
+            color: None,
+            order,
+            count,
+        }
+    }
+
+    fn user(id: Option<i64>, name: &str, count: i64) -> FilterUserDto {
+        FilterUserDto {
+            id,
+            full_name: name.to_string(),
+            count,
+        }
+    }
+
+    fn project(
+        statuses: Vec<FilterOptionDto>,
+        assigned_to: Vec<FilterUserDto>,
+    ) -> IssueFiltersDataDto {
+        IssueFiltersDataDto {
+            statuses,
+            types: vec![],
+            priorities: vec![],
+            severities: vec![],
+            assigned_to,
+            owners: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn test_statuses_grouped_by_name_across_projects() {
+        let facets = IssueFacets::merge(vec![
+            (
+                "a".to_string(),
+                project(
+                    vec![option(1, "New", 1, 4), option(2, "Closed", 5, 0)],
+                    vec![],
+                ),
+            ),
+            (
+                "b".to_string(),
+                project(
+                    vec![option(1, "new", 2, 3), option(12, "In progress", 3, 1)],
+                    vec![],
+                ),
+            ),
+        ]);
+
+        let names: Vec<_> = facets.statuses.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["New", "In progress", "Closed"]);
+        assert_eq!(
+            facets.statuses[0].ids,
+            vec![
+                ScopedId {
+                    account_id: "a".to_string(),
+                    id: 1
+                },
+                ScopedId {
+                    account_id: "b".to_string(),
+                    id: 1
+                },
+            ]
+        );
+        assert_eq!(facets.statuses[0].count, 7);
+        assert_eq!(facets.statuses[2].count, 0);
+    }
+
+    #[test]
+    fn test_assignees_summed_by_account_and_user_with_unassigned_first() {
+        let facets = IssueFacets::merge(vec![
+            (
+                "a".to_string(),
+                project(vec![], vec![user(Some(7), "Ada", 2), user(None, "", 1)]),
+            ),
+            (
+                "a".to_string(),
+                project(
+                    vec![],
+                    vec![user(Some(7), "Ada", 3), user(Some(8), "Bob", 1)],
+                ),
+            ),
+            (
+                "b".to_string(),
+                project(vec![], vec![user(Some(7), "Eve", 2), user(None, "", 4)]),
+            ),
+        ]);
+
+        let counts: Vec<_> = facets
+            .assignees
+            .iter()
+            .map(|u| (u.account_id.as_deref(), u.user_id, u.count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                (None, None, 5),
+                (Some("a"), Some(7), 5),
+                (Some("a"), Some(8), 1),
+                (Some("b"), Some(7), 2),
+            ]
+        );
+    }
+}
@@ -1,9 +1,20 @@
 use serde::Serialize;
-use taiga_client::models::IssueDto;
+use taiga_client::models::{
+    IssueDetailDto, IssueDto, SearchItemDto, UserStoryDetailDto, UserStoryDto,
+};
+
+/// Which Taiga resource a dashboard row comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    Issue,
+    UserStory,
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Issue {
     pub id: i64,
+    pub kind: ItemKind,
     pub subject: String,
     pub project: i64,
     pub status: i64,
@@ -17,12 +28,16 @@ pub struct Issue {
     pub priority: Option<i64>,
     pub severity: Option<i64>,
     pub issue_type: Option<i64>,
+    pub milestone: Option<i64>,
+    /// Account the row was fetched with, set on the aggregated dashboard
+    pub account_id: Option<String>,
 }
 
 impl From<IssueDto> for Issue {
     fn from(dto: IssueDto) -> Self {
         Self {
             id: dto.id,
+            kind: ItemKind::Issue,
             subject: dto.subject,
             project: dto.project,
             status: dto.status,
@@ -42,6 +57,127 @@ impl From<IssueDto> for Issue {
             priority: dto.priority,
             severity: dto.severity,
             issue_type: dto.type_,
+            milestone: dto.milestone,
+            account_id: None,
+        }
+    }
+}
+
+/// Search rows are hydrated from the detail, which has everything a list row has
+impl From<IssueDetailDto> for Issue {
+    fn from(dto: IssueDetailDto) -> Self {
+        Self {
+            id: dto.id,
+            kind: ItemKind::Issue,
+            subject: dto.subject,
+            project: dto.project,
+            status: dto.status,
+            status_name: dto.status_extra_info.as_ref().map(|s| s.name.clone()),
+            status_color: dto.status_extra_info.as_ref().map(|s| s.color.clone()),
+            owner: dto.owner,
+            assigned_to: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|u| u.full_name_display.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|u| u.photo.clone()),
+            modified_date: Some(dto.modified_date),
+            priority: dto.priority,
+            severity: dto.severity,
+            issue_type: dto.type_,
+            milestone: dto.milestone,
+            account_id: None,
+        }
+    }
+}
+
+impl From<UserStoryDetailDto> for Issue {
+    fn from(dto: UserStoryDetailDto) -> Self {
+        Self {
+            id: dto.id,
+            kind: ItemKind::UserStory,
+            subject: dto.subject,
+            project: dto.project,
+            status: dto.status,
+            status_name: dto.status_extra_info.as_ref().map(|s| s.name.clone()),
+            status_color: dto.status_extra_info.as_ref().map(|s| s.color.clone()),
+            owner: dto.owner,
+            assigned_to: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|u| u.full_name_display.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|u| u.photo.clone()),
+            modified_date: Some(dto.modified_date),
+            priority: None,
+            severity: None,
+            issue_type: None,
+            milestone: dto.milestone,
+            account_id: None,
+        }
+    }
+}
+
+impl Issue {
+    /// Search hits carry no project, so it comes from the searched project
+    /// Only id, subject, status and assignee are known; the rest stays empty.
+    /// Used when the hit's detail cannot be fetched.
+    pub fn from_search_hit(dto: SearchItemDto, project: i64, kind: ItemKind) -> Self {
+        Self {
+            id: dto.id,
+            kind,
+            subject: dto.subject,
+            project,
+            status: dto.status,
+            status_name: None,
+            status_color: None,
+            owner: None,
+            assigned_to: dto.assigned_to,
+            assigned_to_name: None,
+            assigned_to_photo: None,
+            modified_date: None,
+            priority: None,
+            severity: None,
+            issue_type: None,
+            milestone: None,
+            account_id: None,
+        }
+    }
+}
+
+/// User stories have no priority, severity or type; those stay empty
+impl From<UserStoryDto> for Issue {
+    fn from(dto: UserStoryDto) -> Self {
+        Self {
+            id: dto.id,
+            kind: ItemKind::UserStory,
+            subject: dto.subject,
+            project: dto.project,
+            status: dto.status,
+            status_name: dto.status_extra_info.as_ref().map(|s| s.name.clone()),
+            status_color: dto.status_extra_info.as_ref().map(|s| s.color.clone()),
+            owner: dto.owner,
+            assigned_to: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|u| u.full_name_display.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|u| u.photo.clone()),
+            modified_date: dto.modified_date,
+            priority: None,
+            severity: None,
+            issue_type: None,
+            milestone: dto.milestone,
+            account_id: None,
         }
     }
 }
@@ -49,12 +185,15 @@ impl From<IssueDto> for Issue {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use taiga_client::models::{IssueDto, IssueStatusExtraInfo, UserExtraInfo};
+    use taiga_client::models::{
+        IssueDto, IssueStatusExtraInfo, SearchItemDto, UserExtraInfo, UserStoryDto,
+    };
 
     #[test]
     fn test_issue_conversion_from_dto() {
         let dto = IssueDto {
             id: 123,
+            ref_: None,
             subject: "Test Issue".to_string(),
             project: 456,
             status: 1,
@@ -74,11 +213,14 @@ mod tests {
             priority: Some(10),
             severity: Some(20),
             type_: Some(30),
+            milestone: Some(40),
+            version: None,
         };
 
         let issue: Issue = dto.into();
 
         assert_eq!(issue.id, 123);
+        assert_eq!(issue.kind, ItemKind::Issue);
         assert_eq!(issue.subject, "Test Issue");
         assert_eq!(issue.status_name, Some("Open".to_string()));
         assert_eq!(issue.status_color, Some("#ff0000".to_string()));
@@ -95,12 +237,14 @@ mod tests {
         assert_eq!(issue.priority, Some(10));
         assert_eq!(issue.severity, Some(20));
         assert_eq!(issue.issue_type, Some(30));
+        assert_eq!(issue.milestone, Some(40));
     }
 
     #[test]
     fn test_issue_conversion_minimal_dto() {
         let dto = IssueDto {
             id: 123,
+            ref_: None,
             subject: "Minimal".to_string(),
             project: 456,
             status: 1,
@@ -112,6 +256,8 @@ mod tests {
             priority: None,
             severity: None,
             type_: None,
+            milestone: None,
+            version: None,
         };
 
         let issue: Issue = dto.into();
@@ -125,5 +271,89 @@ mod tests {
         assert_eq!(issue.priority, None);
         assert_eq!(issue.severity, None);
         assert_eq!(issue.issue_type, None);
+        assert_eq!(issue.milestone, None);
+    }
+
+    #[test]
+    fn test_user_story_conversion_leaves_issue_fields_empty() {
+        let dto = UserStoryDto {
+            id: 321,
+            ref_: 12,
+            subject: "Story".to_string(),
+            project: 456,
+            status: 7,
+            status_extra_info: Some(IssueStatusExtraInfo {
+                name: "Ready".to_string(),
+                color: "#00ff00".to_string(),
+                is_closed: false,
+            }),
+            owner: Some(999),
+            assigned_to: Some(789),
+            assigned_to_extra_info: None,
+            modified_date: Some("2023-01-03T12:00:00Z".to_string()),
+            total_points: Some(3.0),
+            milestone: Some(40),
+            is_closed: false,
+            is_blocked: false,
+        };
+
+        let issue: Issue = dto.into();
+
+        assert_eq!(issue.id, 321);
+        assert_eq!(issue.kind, ItemKind::UserStory);
+        assert_eq!(issue.status_name, Some("Ready".to_string()));
+        assert_eq!(issue.assigned_to, Some(789));
+        assert_eq!(issue.milestone, Some(40));
+        assert_eq!(issue.priority, None);
+        assert_eq!(issue.severity, None);
+        assert_eq!(issue.issue_type, None);
+    }
+
+    #[test]
+    fn test_search_hit_takes_project_from_caller() {
+        let dto = SearchItemDto {
+            id: 5,
+            ref_: 12,
+            subject: "Login crash".to_string(),
+            status: 3,
+            assigned_to: Some(789),
+        };
+
+        let issue = Issue::from_search_hit(dto, 456, ItemKind::Issue);
+
+        assert_eq!(issue.project, 456);
+        assert_eq!(issue.kind, ItemKind::Issue);
+        assert_eq!(issue.status, 3);
+        assert_eq!(issue.assigned_to, Some(789));
+        assert_eq!(issue.status_name, None);
+    }
+
+    #[test]
+    fn test_issue_conversion_from_user_story_detail() {
+        let dto: UserStoryDetailDto = serde_json::from_value(serde_json::json!({
+            "id": 12,
+            "ref": 4,
+            "subject": "Export",
+            "project": 456,
+            "project_extra_info": { "id": 456, "name": "Larch", "slug": "larch" },
+            "status": 2,
+            "status_extra_info": { "name": "Ready", "color": "#00ff00", "is_closed": false },
+            "owner": 999,
+            "created_date": "2023-01-01T12:00:00Z",
+            "modified_date": "2023-01-02T12:00:00Z",
+            "milestone": 7,
+            "version": 3
+        }))
+        .unwrap();
+
+        let issue = Issue::from(dto);
+
+        assert_eq!(issue.kind, ItemKind::UserStory);
+        assert_eq!(issue.status_name.as_deref(), Some("Ready"));
+        assert_eq!(issue.status_color.as_deref(), Some("#00ff00"));
+        assert_eq!(issue.owner, Some(999));
+        assert_eq!(issue.modified_date.as_deref(), Some("2023-01-02T12:00:00Z"));
+        assert_eq!(issue.milestone, Some(7));
+        assert_eq!(issue.priority, None);
     }
 }
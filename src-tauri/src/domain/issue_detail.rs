@@ -1,5 +1,7 @@
 use serde::Serialize;
-use taiga_client::models::{AttachmentDto, IssueDetailDto, IssueHistoryEntryDto, IssueNeighborDto};
+use taiga_client::models::{
+    AttachmentDto, IssueDetailDto, IssueDto, IssueHistoryEntryDto, IssueNeighborDto, VoterDto,
+};
 
 // ============================================================================
 // Issue Detail Domain Models
@@ -51,6 +53,11 @@ pub struct IssueDetail {
     pub watchers: Vec<i64>,
     pub total_watchers: i64,
 
+    // Current user's subscription and vote
+    pub is_watcher: bool,
+    pub is_voter: bool,
+    pub total_voters: i64,
+
     // Blocking
     pub is_blocked: bool,
     pub blocked_note: Option<String>,
@@ -62,6 +69,9 @@ pub struct IssueDetail {
     pub modified_date: String,
     pub finished_date: Option<String>,
 
+    // Sprint
+    pub milestone_id: Option<i64>,
+
     // Navigation & versioning
     pub version: i64,
     pub next_issue: Option<IssueNeighbor>,
@@ -93,6 +103,28 @@ pub struct Attachment {
     pub created_date: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Voter {
+    pub id: i64,
+    pub username: String,
+    /// Falls back to the username when Taiga has no full name
+    pub name: String,
+}
+
+impl From<VoterDto> for Voter {
+    fn from(dto: VoterDto) -> Self {
+        let name = dto
+            .full_name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| dto.username.clone());
+        Self {
+            id: dto.id,
+            username: dto.username,
+            name,
+        }
+    }
+}
+
 // ============================================================================
 // History / Comment Domain Models
 // ============================================================================
@@ -253,24 +285,7 @@ impl IssueDetail {
             .map(|s| s.is_closed)
             .unwrap_or(dto.is_closed);
 
-        // Parse tags from JSON Value - handles null colors
-        let tags: Vec<Tag> = dto
-            .tags
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|t| {
-                        let tag_arr = t.as_array()?;
-                        let name = tag_arr.first()?.as_str()?.to_string();
-                        let color = tag_arr
-                            .get(1)
-                            .and_then(|c| c.as_str())
-                            .map(|s| s.to_string());
-                        Some(Tag { name, color })
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+        let tags = parse_tags(&dto.tags);
 
         let attachments: Vec<Attachment> = dto.attachments.iter().map(|a| a.into()).collect();
 
@@ -331,6 +346,9 @@ impl IssueDetail {
             attachments,
             watchers: dto.watchers,
             total_watchers: dto.total_watchers,
+            is_watcher: dto.is_watcher,
+            is_voter: dto.is_voter,
+            total_voters: dto.total_voters,
             is_blocked: dto.is_blocked,
             blocked_note: dto.blocked_note,
             due_date: dto.due_date,
@@ -338,17 +356,100 @@ impl IssueDetail {
             created_date: dto.created_date,
             modified_date: dto.modified_date,
             finished_date: dto.finished_date,
+            milestone_id: dto.milestone,
             version: dto.version,
             next_issue,
             previous_issue,
         }
     }
+
+    /// Detail built from the response to creating an issue
+    /// Used when the full detail cannot be fetched; fields the create response
+    /// lacks, such as the project name and attachments, stay empty.
+    pub fn from_created(dto: IssueDto) -> Self {
+        let status = dto.status_extra_info.as_ref();
+        let assignee = dto.assigned_to_extra_info.as_ref();
+        let modified_date = dto.modified_date.unwrap_or_default();
+
+        Self {
+            id: dto.id,
+            ref_number: dto.ref_.unwrap_or_default(),
+            subject: dto.subject,
+            description: None,
+            description_html: None,
+            project_id: dto.project,
+            project_name: String::new(),
+            project_slug: String::new(),
+            status_id: dto.status,
+            status_name: status
+                .map(|s| s.name.clone())
+                .unwrap_or_else(|| format!("Status {}", dto.status)),
+            status_color: status
+                .map(|s| s.color.clone())
+                .unwrap_or_else(|| "#808080".to_string()),
+            is_closed: status.is_some_and(|s| s.is_closed),
+            type_id: dto.type_,
+            type_name: None,
+            type_color: None,
+            severity_id: dto.severity,
+            severity_name: None,
+            severity_color: None,
+            priority_id: dto.priority,
+            priority_name: None,
+            priority_color: None,
+            owner_id: dto.owner,
+            owner_name: None,
+            owner_username: None,
+            owner_photo: None,
+            assigned_to_id: dto.assigned_to,
+            assigned_to_name: assignee.map(|a| a.full_name_display.clone()),
+            assigned_to_username: assignee.map(|a| a.username.clone()),
+            assigned_to_photo: assignee.and_then(|a| a.photo.clone()),
+            tags: Vec::new(),
+            attachments: Vec::new(),
+            watchers: Vec::new(),
+            total_watchers: 0,
+            is_watcher: false,
+            is_voter: false,
+            total_voters: 0,
+            is_blocked: false,
+            blocked_note: None,
+            due_date: None,
+            due_date_status: None,
+            created_date: modified_date.clone(),
+            modified_date,
+            finished_date: None,
+            milestone_id: dto.milestone,
+            version: dto.version.unwrap_or_default(),
+            next_issue: None,
+            previous_issue: None,
+        }
+    }
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Parse tags from JSON Value - handles null colors
+pub(crate) fn parse_tags(tags: &serde_json::Value) -> Vec<Tag> {
+    tags.as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|t| {
+                    let tag_arr = t.as_array()?;
+                    let name = tag_arr.first()?.as_str()?.to_string();
+                    let color = tag_arr
+                        .get(1)
+                        .and_then(|c| c.as_str())
+                        .map(|s| s.to_string());
+                    Some(Tag { name, color })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Format file size in human-readable format
 fn format_file_size(bytes: i64) -> String {
     const KB: i64 = 1024;
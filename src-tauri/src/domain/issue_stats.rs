@@ -0,0 +1,322 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+use taiga_client::models::{IssueStatsEntryDto, ProjectIssuesStatsDto};
+
+// ============================================================================
+// Issue Statistics Domain Models
+// ============================================================================
+
+/// Issue count for a status, type, priority or severity
+/// Options from different projects are grouped by name.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatCount {
+    pub name: String,
+    pub color: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssigneeCount {
+    /// Account the user belongs to; `None` for unassigned, which spans every account
+    pub account_id: Option<String>,
+    /// `None` counts unassigned issues
+    pub user_id: Option<i64>,
+    pub name: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyIssueCount {
+    /// `YYYY-MM-DD`
+    pub day: String,
+    pub open: i64,
+    pub closed: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueStats {
+    pub total: i64,
+    pub open: i64,
+    pub closed: i64,
+    pub by_status: Vec<StatCount>,
+    pub by_type: Vec<StatCount>,
+    pub by_priority: Vec<StatCount>,
+    pub by_severity: Vec<StatCount>,
+    pub by_assignee: Vec<AssigneeCount>,
+    /// Open and closed issues on each of the last four weeks' days, ending today
+    pub per_day: Vec<DailyIssueCount>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectIssueStats {
+    pub account_id: String,
+    pub project_id: i64,
+    pub stats: IssueStats,
+}
+
+/// Stats of every project in a view plus their sum, for the dashboard charts
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueStatsDashboard {
+    pub projects: Vec<ProjectIssueStats>,
+    pub combined: IssueStats,
+}
+
+impl IssueStatsDashboard {
+    /// Build the charts from each project's stats, keyed by account and project ID
+    pub fn from_projects(
+        projects: Vec<(String, i64, ProjectIssuesStatsDto)>,
+        today: NaiveDate,
+    ) -> Self {
+        let combined = IssueStats::merge(
+            projects
+                .iter()
+                .map(|(account_id, _, dto)| (account_id.as_str(), dto)),
+            today,
+        );
+        let projects = projects
+            .iter()
+            .map(|(account_id, project_id, dto)| ProjectIssueStats {
+                account_id: account_id.clone(),
+                project_id: *project_id,
+                stats: IssueStats::merge([(account_id.as_str(), dto)], today),
+            })
+            .collect();
+        Self { projects, combined }
+    }
+}
+
+impl IssueStats {
+    /// Sum the stats of several projects, each with its account
+    /// Daily series are aligned on their last entry, which Taiga reports for today.
+    pub fn merge<'a>(
+        projects: impl IntoIterator<Item = (&'a str, &'a ProjectIssuesStatsDto)>,
+        today: NaiveDate,
+    ) -> Self {
+        let mut total = 0;
+        let mut open = 0;
+        let mut closed = 0;
+        let mut statuses = Vec::new();
+        let mut types = Vec::new();
+        let mut priorities = Vec::new();
+        let mut severities = Vec::new();
+        let mut assignees = Vec::new();
+        // Indexed from the end: 0 is today
+        let mut days: Vec<(i64, i64)> = Vec::new();
+
+        for (account_id, project) in projects {
+            total += project.total_issues;
+            open += project.opened_issues;
+            closed += project.closed_issues;
+            statuses.extend(project.issues_per_status.values());
+            types.extend(project.issues_per_type.values());
+            priorities.extend(project.issues_per_priority.values());
+            severities.extend(project.issues_per_severity.values());
+            assignees.extend(
+                project
+                    .issues_per_assigned_to
+                    .values()
+                    .map(|entry| (account_id, entry)),
+            );
+
+            let series = &project.last_four_weeks_days.by_open_closed;
+            let len = series.open.len().max(series.closed.len());
+            if days.len() < len {
+                days.resize(len, (0, 0));
+            }
+            for (i, count) in series.open.iter().rev().enumerate() {
+                days[i].0 += count;
+            }
+            for (i, count) in series.closed.iter().rev().enumerate() {
+                days[i].1 += count;
+            }
+        }
+
+        let per_day = days
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(days_ago, (open, closed))| DailyIssueCount {
+                day: (today - Duration::days(days_ago as i64))
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                open: *open,
+                closed: *closed,
+            })
+            .collect();
+
+        Self {
+            total,
+            open,
+            closed,
+            by_status: merge_counts(statuses),
+            by_type: merge_counts(types),
+            by_priority: merge_counts(priorities),
+            by_severity: merge_counts(severities),
+            by_assignee: merge_assignees(assignees),
+            per_day,
+        }
+    }
+}
+
+/// Group by case-insensitive name; largest count first
+fn merge_counts(entries: Vec<&IssueStatsEntryDto>) -> Vec<StatCount> {
+    let mut merged: Vec<StatCount> = Vec::new();
+
+    for entry in entries {
+        let key = entry.name.trim().to_lowercase();
+        match merged
+            .iter_mut()
+            .find(|c| c.name.trim().to_lowercase() == key)
+        {
+            Some(existing) => existing.count += entry.count,
+            None => merged.push(StatCount {
+                name: entry.name.clone(),
+                color: entry.color.clone(),
+                count: entry.count,
+            }),
+        }
+    }
+
+    merged.sort_by(|a, b| b.count.cmp(&a.count).then(a.name.cmp(&b.name)));
+    merged
+}
+
+/// Group by account and user ID; unassigned comes first, then largest count
+fn merge_assignees(entries: Vec<(&str, &IssueStatsEntryDto)>) -> Vec<AssigneeCount> {
+    let mut merged: HashMap<(Option<String>, Option<i64>), AssigneeCount> = HashMap::new();
+
+    for (account_id, entry) in entries {
+        let user_id = entry.id.filter(|id| *id != 0);
+        let account_id = user_id.map(|_| account_id.to_string());
+        merged
+            .entry((account_id.clone(), user_id))
+            .and_modify(|a| a.count += entry.count)
+            .or_insert_with(|| AssigneeCount {
+                account_id,
+                user_id,
+                name: entry.name.clone(),
+                count: entry.count,
+            });
+    }
+
+    let mut merged: Vec<AssigneeCount> = merged.into_values().collect();
+    merged.sort_by(|a, b| {
+        a.user_id
+            .is_some()
+            .cmp(&b.user_id.is_some())
+            .then(b.count.cmp(&a.count))
+            .then(a.name.cmp(&b.name))
+    });
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taiga_client::models::{IssueOpenClosedDaysDto, IssueStatsDaysDto};
+
+    fn entry(id: i64, name: &str, count: i64) -> (String, IssueStatsEntryDto) {
+        (
+            id.to_string(),
+            IssueStatsEntryDto {
+                id: Some(id),
+                name: name.to_string(),
+                color: None,
+                count,
+            },
+        )
+    }
+
+    fn project(
+        priorities: Vec<(String, IssueStatsEntryDto)>,
+        assignees: Vec<(String, IssueStatsEntryDto)>,
+        open_per_day: Vec<i64>,
+    ) -> ProjectIssuesStatsDto {
+        ProjectIssuesStatsDto {
+            total_issues: 4,
+            opened_issues: 3,
+            closed_issues: 1,
+            issues_per_status: HashMap::new(),
+            issues_per_type: HashMap::new(),
+            issues_per_priority: priorities.into_iter().collect(),
+            issues_per_severity: HashMap::new(),
+            issues_per_assigned_to: assignees.into_iter().collect(),
+            last_four_weeks_days: IssueStatsDaysDto {
+                by_open_closed: IssueOpenClosedDaysDto {
+                    closed: vec![0; open_per_day.len()],
+                    open: open_per_day,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_merge_sums_totals_and_groups_by_name_and_user() {
+        let projects = [
+            project(
+                vec![entry(1, "High", 2), entry(2, "Low", 1)],
+                vec![entry(0, "Unassigned", 1), entry(7, "Ada", 2)],
+                vec![],
+            ),
+            project(
+                vec![entry(11, "high", 3)],
+                vec![entry(7, "Ada", 1), entry(8, "Bob", 4)],
+                vec![],
+            ),
+            project(vec![], vec![entry(7, "Eve", 1)], vec![]),
+        ];
+        let stats = IssueStats::merge(
+            [
+                ("a", &projects[0]),
+                ("a", &projects[1]),
+                ("b", &projects[2]),
+            ],
+            NaiveDate::from_ymd_opt(2024, 3, 10).unwrap(),
+        );
+
+        assert_eq!((stats.total, stats.open, stats.closed), (12, 9, 3));
+        let priorities: Vec<_> = stats
+            .by_priority
+            .iter()
+            .map(|p| (p.name.as_str(), p.count))
+            .collect();
+        assert_eq!(priorities, vec![("High", 5), ("Low", 1)]);
+        let assignees: Vec<_> = stats
+            .by_assignee
+            .iter()
+            .map(|a| (a.account_id.as_deref(), a.user_id, a.count))
+            .collect();
+        assert_eq!(
+            assignees,
+            vec![
+                (None, None, 1),
+                (Some("a"), Some(8), 4),
+                (Some("a"), Some(7), 3),
+                (Some("b"), Some(7), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_daily_series_aligned_on_today() {
+        let projects = [
+            project(vec![], vec![], vec![1, 2, 3]),
+            project(vec![], vec![], vec![10, 20]),
+        ];
+        let stats = IssueStats::merge(
+            projects.iter().map(|p| ("a", p)),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+        );
+
+        let days: Vec<_> = stats
+            .per_day
+            .iter()
+            .map(|d| (d.day.as_str(), d.open))
+            .collect();
+        assert_eq!(
+            days,
+            vec![("2024-02-28", 1), ("2024-02-29", 12), ("2024-03-01", 23)]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use taiga_client::models::{MilestoneDto, MilestoneStatsDto};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Milestone {
+    pub id: i64,
+    pub name: String,
+    pub project: i64,
+    pub estimated_start: String,
+    pub estimated_finish: String,
+    pub closed: bool,
+    pub total_points: Option<f64>,
+    pub closed_points: Option<f64>,
+    /// Whether today falls within the sprint's estimated dates
+    pub is_current: bool,
+}
+
+impl Milestone {
+    pub fn from_dto(dto: MilestoneDto, today: NaiveDate) -> Self {
+        let is_current = is_current(&dto, today);
+        Self {
+            id: dto.id,
+            name: dto.name,
+            project: dto.project,
+            estimated_start: dto.estimated_start,
+            estimated_finish: dto.estimated_finish,
+            closed: dto.closed,
+            total_points: dto.total_points,
+            closed_points: dto.closed_points,
+            is_current,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BurndownDay {
+    pub day: String,
+    pub open_points: Option<f64>,
+    pub optimal_points: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneStats {
+    pub name: String,
+    pub estimated_start: String,
+    pub estimated_finish: String,
+    pub total_points: f64,
+    pub completed_points: f64,
+    pub total_user_stories: i64,
+    pub completed_user_stories: i64,
+    pub total_tasks: i64,
+    pub completed_tasks: i64,
+    pub burndown: Vec<BurndownDay>,
+}
+
+impl From<MilestoneStatsDto> for MilestoneStats {
+    fn from(dto: MilestoneStatsDto) -> Self {
+        Self {
+            name: dto.name,
+            estimated_start: dto.estimated_start,
+            estimated_finish: dto.estimated_finish,
+            total_points: sum_points(&dto.total_points),
+            completed_points: sum_points(&dto.completed_points),
+            total_user_stories: dto.total_userstories,
+            completed_user_stories: dto.completed_userstories,
+            total_tasks: dto.total_tasks,
+            completed_tasks: dto.completed_tasks,
+            burndown: dto
+                .days
+                .into_iter()
+                .map(|d| BurndownDay {
+                    day: d.day,
+                    open_points: d.open_points,
+                    optimal_points: d.optimal_points,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The open sprint whose estimated dates contain `today`
+/// When sprints overlap, the one that started last wins.
+pub fn current_milestone_id(milestones: &[MilestoneDto], today: NaiveDate) -> Option<i64> {
+    milestones
+        .iter()
+        .filter(|m| !m.closed && is_current(m, today))
+        .max_by_key(|m| parse_date(&m.estimated_start))
+        .map(|m| m.id)
+}
+
+fn is_current(milestone: &MilestoneDto, today: NaiveDate) -> bool {
+    match (
+        parse_date(&milestone.estimated_start),
+        parse_date(&milestone.estimated_finish),
+    ) {
+        (Some(start), Some(finish)) => start <= today && today <= finish,
+        _ => false,
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// Points come as `{role: points}` maps or plain lists; anything non-numeric counts as zero
+fn sum_points(value: &serde_json::Value) -> f64 {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64().unwrap_or(0.0),
+        serde_json::Value::Array(items) => items.iter().map(sum_points).sum(),
+        serde_json::Value::Object(map) => map.values().map(sum_points).sum(),
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestone(id: i64, start: &str, finish: &str, closed: bool) -> MilestoneDto {
+        MilestoneDto {
+            id,
+            name: format!("Sprint {}", id),
+            slug: format!("sprint-{}", id),
+            project: 1,
+            estimated_start: start.to_string(),
+            estimated_finish: finish.to_string(),
+            closed,
+            total_points: None,
+            closed_points: None,
+            created_date: None,
+            modified_date: None,
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_current_milestone_contains_today() {
+        let milestones = [
+            milestone(1, "2024-03-01", "2024-03-14", false),
+            milestone(2, "2024-03-15", "2024-03-28", false),
+        ];
+
+        assert_eq!(
+            current_milestone_id(&milestones, date("2024-03-15")),
+            Some(2)
+        );
+        assert_eq!(
+            current_milestone_id(&milestones, date("2024-03-14")),
+            Some(1)
+        );
+        assert_eq!(current_milestone_id(&milestones, date("2024-04-01")), None);
+    }
+
+    #[test]
+    fn test_current_milestone_skips_closed_and_prefers_latest_start() {
+        let milestones = [
+            milestone(1, "2024-03-01", "2024-03-31", false),
+            milestone(2, "2024-03-10", "2024-03-20", false),
+            milestone(3, "2024-03-12", "2024-03-20", true),
+        ];
+
+        assert_eq!(
+            current_milestone_id(&milestones, date("2024-03-15")),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_sum_points_handles_maps_and_lists() {
+        assert_eq!(sum_points(&serde_json::json!({ "3": 13.0, "4": 8 })), 21.0);
+        assert_eq!(sum_points(&serde_json::json!([5.0, 2.5])), 7.5);
+        assert_eq!(sum_points(&serde_json::Value::Null), 0.0);
+    }
+}
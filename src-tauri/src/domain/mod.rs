@@ -1,6 +1,14 @@
 // This module will contain the domain models of the application.
 
+pub mod custom_attribute;
+pub mod epic;
+pub mod facets;
 pub mod issue;
 pub mod issue_detail;
+pub mod issue_stats;
+pub mod milestone;
 pub mod project;
+pub mod task;
 pub mod user;
+pub mod user_story;
+pub mod wiki;
@@ -1,6 +1,8 @@
 use serde::Serialize;
 use taiga_client::models::{IssueTypeDto, PriorityDto, ProjectDto, SeverityDto};
 
+use crate::domain::custom_attribute::CustomAttribute;
+
 #[derive(Debug, Clone, Serialize)]
 pub struct IssueStatus {
     pub id: i64,
@@ -14,6 +16,7 @@ pub struct Member {
     pub id: i64,
     pub user_id: Option<i64>,
     pub full_name: String,
+    pub username: Option<String>,
     pub role_name: String,
     pub photo: Option<String>,
 }
@@ -85,11 +88,14 @@ pub struct TagColor {
 pub struct ProjectMetadata {
     pub id: i64,
     pub statuses: Vec<IssueStatus>,
+    pub user_story_statuses: Vec<IssueStatus>,
+    pub task_statuses: Vec<IssueStatus>,
     pub members: Vec<Member>,
     pub priorities: Vec<Priority>,
     pub severities: Vec<Severity>,
     pub issue_types: Vec<IssueType>,
     pub tags_colors: Vec<TagColor>,
+    pub issue_custom_attributes: Vec<CustomAttribute>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -0,0 +1,225 @@
+use serde::Serialize;
+use taiga_client::models::{TaskDetailDto, TaskDto};
+
+use crate::domain::issue_detail::{parse_tags, Attachment, Tag};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Task {
+    pub id: i64,
+    pub ref_number: i64,
+    pub subject: String,
+    pub project: i64,
+    pub user_story_id: Option<i64>,
+    pub user_story_ref: Option<i64>,
+    pub user_story_subject: Option<String>,
+    pub status: i64,
+    pub status_name: Option<String>,
+    pub status_color: Option<String>,
+    pub is_closed: bool,
+    pub assigned_to: Option<i64>,
+    pub assigned_to_name: Option<String>,
+    pub assigned_to_photo: Option<String>,
+    pub modified_date: Option<String>,
+}
+
+impl From<TaskDto> for Task {
+    fn from(dto: TaskDto) -> Self {
+        Self {
+            id: dto.id,
+            ref_number: dto.ref_,
+            subject: dto.subject,
+            project: dto.project,
+            user_story_id: dto.user_story,
+            user_story_ref: dto.user_story_extra_info.as_ref().map(|us| us.ref_),
+            user_story_subject: dto
+                .user_story_extra_info
+                .as_ref()
+                .map(|us| us.subject.clone()),
+            status: dto.status,
+            status_name: dto.status_extra_info.as_ref().map(|s| s.name.clone()),
+            status_color: dto.status_extra_info.as_ref().map(|s| s.color.clone()),
+            is_closed: dto
+                .status_extra_info
+                .as_ref()
+                .map(|s| s.is_closed)
+                .unwrap_or(dto.is_closed),
+            assigned_to: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|u| u.full_name_display.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|u| u.photo.clone()),
+            modified_date: dto.modified_date,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskDetail {
+    pub id: i64,
+    pub ref_number: i64,
+    pub subject: String,
+    pub description: Option<String>,
+    pub description_html: Option<String>,
+
+    // Project and parent story
+    pub project_id: i64,
+    pub project_name: String,
+    pub project_slug: String,
+    pub user_story_id: Option<i64>,
+    pub user_story_ref: Option<i64>,
+    pub user_story_subject: Option<String>,
+
+    // Status with is_closed distinction
+    pub status_id: i64,
+    pub status_name: String,
+    pub status_color: String,
+    pub is_closed: bool,
+
+    // People
+    pub owner_id: Option<i64>,
+    pub owner_name: Option<String>,
+    pub assigned_to_id: Option<i64>,
+    pub assigned_to_name: Option<String>,
+    pub assigned_to_username: Option<String>,
+    pub assigned_to_photo: Option<String>,
+
+    // Collections
+    pub tags: Vec<Tag>,
+    pub attachments: Vec<Attachment>,
+    pub watchers: Vec<i64>,
+
+    // Blocking
+    pub is_blocked: bool,
+    pub blocked_note: Option<String>,
+    pub is_iocaine: bool,
+
+    // Dates
+    pub due_date: Option<String>,
+    pub created_date: String,
+    pub modified_date: String,
+    pub finished_date: Option<String>,
+
+    pub version: i64,
+}
+
+impl TaskDetail {
+    pub fn from_dto(dto: TaskDetailDto) -> Self {
+        let status_name = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("Status {}", dto.status));
+        let status_color = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.color.clone())
+            .unwrap_or_else(|| "#808080".to_string());
+        let is_closed = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.is_closed)
+            .unwrap_or(dto.is_closed);
+
+        Self {
+            id: dto.id,
+            ref_number: dto.ref_,
+            subject: dto.subject,
+            description: dto.description,
+            description_html: dto.description_html,
+            project_id: dto.project,
+            project_name: dto.project_extra_info.name,
+            project_slug: dto.project_extra_info.slug,
+            user_story_id: dto.user_story,
+            user_story_ref: dto.user_story_extra_info.as_ref().map(|us| us.ref_),
+            user_story_subject: dto.user_story_extra_info.map(|us| us.subject),
+            status_id: dto.status,
+            status_name,
+            status_color,
+            is_closed,
+            owner_id: dto.owner,
+            owner_name: dto
+                .owner_extra_info
+                .as_ref()
+                .map(|o| o.full_name_display.clone()),
+            assigned_to_id: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|a| a.full_name_display.clone()),
+            assigned_to_username: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|a| a.username.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|a| a.photo.clone()),
+            tags: parse_tags(&dto.tags),
+            attachments: dto.attachments.iter().map(|a| a.into()).collect(),
+            watchers: dto.watchers,
+            is_blocked: dto.is_blocked,
+            blocked_note: dto.blocked_note,
+            is_iocaine: dto.is_iocaine,
+            due_date: dto.due_date,
+            created_date: dto.created_date,
+            modified_date: dto.modified_date,
+            finished_date: dto.finished_date,
+            version: dto.version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taiga_client::models::UserStoryExtraInfo;
+
+    fn task_dto(user_story: Option<i64>, extra: Option<UserStoryExtraInfo>) -> TaskDto {
+        TaskDto {
+            id: 9,
+            ref_: 51,
+            subject: "Write migration".to_string(),
+            project: 1,
+            user_story,
+            user_story_extra_info: extra,
+            status: 4,
+            status_extra_info: None,
+            owner: None,
+            assigned_to: None,
+            assigned_to_extra_info: None,
+            modified_date: None,
+            is_closed: true,
+            is_blocked: false,
+        }
+    }
+
+    #[test]
+    fn test_task_links_parent_story() {
+        let task: Task = task_dto(
+            Some(3),
+            Some(UserStoryExtraInfo {
+                id: 3,
+                ref_: 43,
+                subject: "Story 3".to_string(),
+            }),
+        )
+        .into();
+
+        assert_eq!(task.user_story_id, Some(3));
+        assert_eq!(task.user_story_ref, Some(43));
+        assert_eq!(task.user_story_subject, Some("Story 3".to_string()));
+        // Without status info the task's own flag is used
+        assert!(task.is_closed);
+    }
+
+    #[test]
+    fn test_task_without_parent_story() {
+        let task: Task = task_dto(None, None).into();
+        assert_eq!(task.user_story_id, None);
+        assert_eq!(task.user_story_ref, None);
+    }
+}
@@ -0,0 +1,216 @@
+use serde::Serialize;
+use taiga_client::models::UserStoryDetailDto;
+
+use crate::domain::issue_detail::{parse_tags, Attachment, IssueNeighbor, Tag};
+
+// ============================================================================
+// User Story Detail Domain Model
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserStoryDetail {
+    pub id: i64,
+    pub ref_number: i64,
+    pub subject: String,
+    pub description: Option<String>,
+    pub description_html: Option<String>,
+
+    // Project info
+    pub project_id: i64,
+    pub project_name: String,
+    pub project_slug: String,
+
+    // Status with is_closed distinction
+    pub status_id: i64,
+    pub status_name: String,
+    pub status_color: String,
+    pub is_closed: bool,
+
+    // Estimation and requirements
+    pub total_points: Option<f64>,
+    pub client_requirement: bool,
+    pub team_requirement: bool,
+
+    // People
+    pub owner_id: Option<i64>,
+    pub owner_name: Option<String>,
+    pub owner_photo: Option<String>,
+    pub assigned_to_id: Option<i64>,
+    pub assigned_to_name: Option<String>,
+    pub assigned_to_username: Option<String>,
+    pub assigned_to_photo: Option<String>,
+
+    // Collections
+    pub tags: Vec<Tag>,
+    pub attachments: Vec<Attachment>,
+    pub watchers: Vec<i64>,
+    pub total_watchers: i64,
+
+    // Blocking
+    pub is_blocked: bool,
+    pub blocked_note: Option<String>,
+
+    // Dates
+    pub due_date: Option<String>,
+    pub due_date_status: Option<String>,
+    pub created_date: String,
+    pub modified_date: String,
+    pub finish_date: Option<String>,
+
+    // Sprint
+    pub milestone_id: Option<i64>,
+
+    // Navigation & versioning
+    pub version: i64,
+    pub next_story: Option<IssueNeighbor>,
+    pub previous_story: Option<IssueNeighbor>,
+}
+
+impl UserStoryDetail {
+    pub fn from_dto(dto: UserStoryDetailDto) -> Self {
+        let status_name = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("Status {}", dto.status));
+        let status_color = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.color.clone())
+            .unwrap_or_else(|| "#808080".to_string());
+        let is_closed = dto
+            .status_extra_info
+            .as_ref()
+            .map(|s| s.is_closed)
+            .unwrap_or(dto.is_closed);
+
+        let next_story = dto
+            .neighbors
+            .as_ref()
+            .and_then(|n| n.next.as_ref())
+            .map(|n| n.into());
+        let previous_story = dto
+            .neighbors
+            .as_ref()
+            .and_then(|n| n.previous.as_ref())
+            .map(|n| n.into());
+
+        Self {
+            id: dto.id,
+            ref_number: dto.ref_,
+            subject: dto.subject,
+            description: dto.description,
+            description_html: dto.description_html,
+            project_id: dto.project,
+            project_name: dto.project_extra_info.name,
+            project_slug: dto.project_extra_info.slug,
+            status_id: dto.status,
+            status_name,
+            status_color,
+            is_closed,
+            total_points: dto.total_points,
+            client_requirement: dto.client_requirement,
+            team_requirement: dto.team_requirement,
+            owner_id: dto.owner,
+            owner_name: dto
+                .owner_extra_info
+                .as_ref()
+                .map(|o| o.full_name_display.clone()),
+            owner_photo: dto.owner_extra_info.as_ref().and_then(|o| o.photo.clone()),
+            assigned_to_id: dto.assigned_to,
+            assigned_to_name: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|a| a.full_name_display.clone()),
+            assigned_to_username: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .map(|a| a.username.clone()),
+            assigned_to_photo: dto
+                .assigned_to_extra_info
+                .as_ref()
+                .and_then(|a| a.photo.clone()),
+            tags: parse_tags(&dto.tags),
+            attachments: dto.attachments.iter().map(|a| a.into()).collect(),
+            watchers: dto.watchers,
+            total_watchers: dto.total_watchers,
+            is_blocked: dto.is_blocked,
+            blocked_note: dto.blocked_note,
+            due_date: dto.due_date,
+            due_date_status: dto.due_date_status,
+            created_date: dto.created_date,
+            modified_date: dto.modified_date,
+            finish_date: dto.finish_date,
+            milestone_id: dto.milestone,
+            version: dto.version,
+            next_story,
+            previous_story,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story_dto(status_extra_info: serde_json::Value) -> UserStoryDetailDto {
+        serde_json::from_value(serde_json::json!({
+            "id": 3,
+            "ref": 43,
+            "subject": "Story 3",
+            "description": null,
+            "description_html": null,
+            "project": 1,
+            "project_extra_info": { "id": 1, "name": "Larch", "slug": "larch", "logo_small_url": null },
+            "status": 2,
+            "status_extra_info": status_extra_info,
+            "is_closed": true,
+            "owner": 7,
+            "owner_extra_info": null,
+            "assigned_to": null,
+            "assigned_to_extra_info": null,
+            "tags": [["backend", null]],
+            "total_points": 5.0,
+            "blocked_note": null,
+            "due_date": null,
+            "due_date_status": null,
+            "created_date": "2024-01-01T10:00:00Z",
+            "modified_date": "2024-01-02T10:00:00Z",
+            "finish_date": null,
+            "version": 4,
+            "neighbors": {
+                "next": { "id": 4, "ref": 44, "subject": "Story 4" },
+                "previous": null
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_user_story_detail_uses_status_info() {
+        let story = UserStoryDetail::from_dto(story_dto(serde_json::json!({
+            "name": "In progress",
+            "color": "#ff9900",
+            "is_closed": false
+        })));
+
+        assert_eq!(story.ref_number, 43);
+        assert_eq!(story.project_name, "Larch");
+        assert_eq!(story.status_name, "In progress");
+        assert_eq!(story.status_color, "#ff9900");
+        assert!(!story.is_closed);
+        assert_eq!(story.tags.len(), 1);
+        assert_eq!(story.next_story.as_ref().map(|n| n.ref_number), Some(44));
+        assert!(story.previous_story.is_none());
+    }
+
+    #[test]
+    fn test_user_story_detail_without_status_info_falls_back() {
+        let story = UserStoryDetail::from_dto(story_dto(serde_json::Value::Null));
+
+        assert_eq!(story.status_name, "Status 2");
+        assert_eq!(story.status_color, "#808080");
+        // Without status info the story's own flag is used
+        assert!(story.is_closed);
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use taiga_client::models::{WikiLinkDto, WikiPageDto};
+
+// ============================================================================
+// Wiki Domain Models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiPage {
+    pub id: i64,
+    pub project_id: i64,
+    pub slug: String,
+    pub content: String,
+    pub content_html: Option<String>,
+    pub owner_id: Option<i64>,
+    pub last_modifier_id: Option<i64>,
+    pub created_date: Option<String>,
+    pub modified_date: Option<String>,
+    pub editions: i64,
+    pub version: i64,
+}
+
+impl From<WikiPageDto> for WikiPage {
+    fn from(dto: WikiPageDto) -> Self {
+        Self {
+            id: dto.id,
+            project_id: dto.project,
+            slug: dto.slug,
+            content: dto.content,
+            content_html: dto.html,
+            owner_id: dto.owner,
+            last_modifier_id: dto.last_modifier,
+            created_date: dto.created_date,
+            modified_date: dto.modified_date,
+            editions: dto.editions,
+            version: dto.version,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiLink {
+    pub id: i64,
+    pub project_id: i64,
+    pub title: String,
+    /// Slug of the linked wiki page
+    pub slug: String,
+    pub order: i64,
+}
+
+impl From<WikiLinkDto> for WikiLink {
+    fn from(dto: WikiLinkDto) -> Self {
+        Self {
+            id: dto.id,
+            project_id: dto.project,
+            title: dto.title,
+            slug: dto.href,
+            order: dto.order,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 #[derive(Debug, Error, Serialize)]
@@ -9,6 +10,12 @@ pub enum Error {
     #[error("Keyring error: {0}")]
     Keyring(String),
 
+    #[error("Credential store error: {0}")]
+    CredentialStore(String),
+
+    #[error("The credential file is locked. Enter its passphrase to unlock it.")]
+    CredentialsLocked,
+
     #[error("Serde error: {0}")]
     Serde(String),
 
@@ -34,6 +41,25 @@ pub enum Error {
 
     #[error("Unauthorized: authentication required or session expired")]
     Unauthorized,
+
+    #[error("Taiga is rate limiting requests. Please wait a moment and try again.")]
+    RateLimited,
+
+    #[error("Taiga server is unavailable ({0}). Please try again later.")]
+    ServerUnavailable(u16),
+
+    #[error("The transfer was cancelled")]
+    Cancelled,
+
+    #[error("File is too large to upload ({size} bytes, the limit is {limit} bytes)")]
+    FileTooLarge { size: u64, limit: u64 },
+
+    /// Taiga refused the change; `field_errors` maps field names to validation messages
+    #[error("Taiga rejected the request: {message}")]
+    Rejected {
+        message: String,
+        field_errors: BTreeMap<String, Vec<String>>,
+    },
 }
 
 impl From<taiga_client::errors::TaigaClientError> for Error {
@@ -41,6 +67,32 @@ impl From<taiga_client::errors::TaigaClientError> for Error {
         match e {
             taiga_client::errors::TaigaClientError::VersionConflict(_) => Error::VersionConflict,
             taiga_client::errors::TaigaClientError::Unauthorized(_) => Error::Unauthorized,
+            taiga_client::errors::TaigaClientError::RateLimited { .. } => Error::RateLimited,
+            taiga_client::errors::TaigaClientError::ServerError(status) => {
+                Error::ServerUnavailable(status.as_u16())
+            }
+            taiga_client::errors::TaigaClientError::Cancelled => Error::Cancelled,
+            taiga_client::errors::TaigaClientError::FileTooLarge { size, limit } => {
+                Error::FileTooLarge { size, limit }
+            }
+            taiga_client::errors::TaigaClientError::Io(e) => Error::Io(e.to_string()),
+            taiga_client::errors::TaigaClientError::Api(api) => {
+                let message = api.message.clone().unwrap_or_else(|| {
+                    if api.field_errors.is_empty() {
+                        format!("status {}", api.status)
+                    } else {
+                        api.field_errors
+                            .iter()
+                            .map(|(field, reasons)| format!("{}: {}", field, reasons.join(", ")))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    }
+                });
+                Error::Rejected {
+                    message,
+                    field_errors: api.field_errors,
+                }
+            }
             other => Error::TaigaClient(other.to_string()),
         }
     }
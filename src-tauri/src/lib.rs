@@ -5,9 +5,23 @@ pub mod error;
 pub mod repositories;
 pub mod services;
 
-use crate::repositories::Repository;
 use tauri::{Emitter, Manager};
 
+/// Run `complete_sessions` without blocking the caller; failures are only logged
+pub(crate) fn spawn_complete_sessions(app: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let repo = app.state::<repositories::SqliteRepository>();
+        let vault = app.state::<services::credential_store::CredentialVault>();
+        let accounts = app.state::<services::accounts::AccountClients>();
+        if let Err(e) =
+            services::accounts::complete_sessions(repo.inner(), vault.inner(), accounts.inner())
+                .await
+        {
+            log::error!("Failed to restore the remaining sessions: {}", e);
+        }
+    });
+}
+
 #[allow(clippy::missing_panics_doc)]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -27,23 +41,41 @@ pub fn run() {
 
             let repository = repositories::SqliteRepository::new(conn);
 
-            // Restore session
-            let api_url_opt =
-                tauri::async_runtime::block_on(repository.get_config("taiga_api_url"))?;
-            if let Some(api_url) = api_url_opt {
-                log::info!("Found saved API URL: {}", api_url);
-                if let Ok(url) = api_url.parse() {
-                    let client = taiga_client::TaigaClient::new(url);
-                    app.manage(client);
-                    log::info!("Restored Taiga session for {}", api_url);
-                } else {
-                    log::error!("Failed to parse saved API URL: {}", api_url);
-                }
-            } else {
-                log::info!("No saved API URL found in config");
+            // Pick the credential backend; the keyring is probed so a missing
+            // Secret Service can be reported before login fails
+            let vault =
+                tauri::async_runtime::block_on(services::credential_store::CredentialVault::load(
+                    &repository,
+                    &app.path().app_data_dir()?,
+                ))?;
+            let status = vault.status();
+            if status.locked {
+                log::info!("The credential file is locked until its passphrase is entered");
+            } else if !status.keyring_available
+                && status.backend == services::credential_store::CredentialBackend::Keyring
+            {
+                log::warn!(
+                    "No usable keyring; the encrypted credential file can be chosen instead"
+                );
+            }
+
+            // Restore the sessions of all accounts
+            let accounts = tauri::async_runtime::block_on(services::accounts::restore_sessions(
+                &repository,
+                &vault,
+            ))?;
+            if accounts.active_id().is_none() {
+                log::info!("No saved account found in config");
             }
+            app.manage(accounts);
+            app.manage(vault);
 
             app.manage(repository);
+            app.manage(services::transfers::TransferRegistry::default());
+
+            // Legacy accounts need Taiga to find their username; ask in the
+            // background so a slow or offline server does not hold up startup
+            spawn_complete_sessions(app.handle().clone());
 
             Ok(())
         })
@@ -53,10 +85,13 @@ pub fn run() {
                 let uri = request.uri().to_string();
                 let target_url = uri.replace("taiga-auth://", "https://");
 
-                let client = match app_handle.try_state::<taiga_client::TaigaClient>() {
-                    Some(c) => c,
+                let accounts = match app_handle.try_state::<services::accounts::AccountClients>() {
+                    Some(a) => a,
                     None => {
-                        log::error!("TaigaClient not found in state when fetching {}", target_url);
+                        log::error!(
+                            "AccountClients not found in state when fetching {}",
+                            target_url
+                        );
                         if let Ok(response) = tauri::http::Response::builder()
                             .status(500)
                             .header("Access-Control-Allow-Origin", "*")
@@ -68,7 +103,7 @@ pub fn run() {
                     }
                 };
 
-                if !client.is_managed_url(&target_url) {
+                let Some(client) = accounts.for_url(&target_url) else {
                     log::warn!("Rejected request to unmanaged URL: {}", target_url);
                     if let Ok(response) = tauri::http::Response::builder()
                         .status(403)
@@ -78,11 +113,15 @@ pub fn run() {
                         responder.respond(response);
                     }
                     return;
-                }
+                };
 
-                let token = crate::services::credentials::get_api_token().ok();
+                let token = client.api_token().ok();
 
-                match client.get_raw_resource(&target_url, token.as_ref()).await {
+                match client
+                    .client()
+                    .get_raw_resource(&target_url, token.as_ref())
+                    .await
+                {
                     Ok((bytes, mime)) => {
                         match tauri::http::Response::builder()
                             .header("Access-Control-Allow-Origin", "*")
@@ -92,9 +131,8 @@ pub fn run() {
                             Ok(response) => responder.respond(response),
                             Err(e) => {
                                 log::error!("Failed to build response for {}: {}", target_url, e);
-                                if let Ok(fallback) = tauri::http::Response::builder()
-                                    .status(500)
-                                    .body(vec![])
+                                if let Ok(fallback) =
+                                    tauri::http::Response::builder().status(500).body(vec![])
                                 {
                                     responder.respond(fallback);
                                 }
@@ -103,9 +141,8 @@ pub fn run() {
                     }
                     Err(e) => {
                         log::error!("Failed to proxy image {}: {}", target_url, e);
-                        if let Ok(response) = tauri::http::Response::builder()
-                            .status(404)
-                            .body(vec![])
+                        if let Ok(response) =
+                            tauri::http::Response::builder().status(404).body(vec![])
                         {
                             responder.respond(response);
                         }
@@ -121,21 +158,50 @@ pub fn run() {
             commands::auth_commands::logout,
             commands::auth_commands::refresh_token,
             commands::auth_commands::get_taiga_base_url,
+            commands::app_commands::get_connection_settings,
+            commands::app_commands::save_connection_settings,
             commands::auth_commands::get_taiga_api_url,
+            commands::account_commands::list_accounts,
+            commands::account_commands::switch_account,
+            commands::account_commands::remove_account,
+            commands::credential_commands::get_credential_store_status,
+            commands::credential_commands::unlock_credential_store,
+            commands::credential_commands::set_credential_backend,
             commands::user_commands::get_me,
             commands::project_commands::get_projects,
             commands::project_commands::list_issues,
             commands::project_commands::get_selected_projects,
             commands::project_commands::save_selected_projects,
             commands::project_commands::get_aggregated_issues,
+            commands::project_commands::search_issues,
+            commands::project_commands::get_issue_facets,
+            commands::project_commands::get_view_issue_stats,
             commands::project_commands::get_project_metadata,
             commands::issue_commands::get_issue_detail,
+            commands::issue_commands::resolve_issue_reference,
             commands::issue_commands::get_issue_history,
+            commands::issue_commands::create_issue,
+            commands::issue_commands::delete_issue,
+            commands::issue_commands::promote_issue_to_user_story,
             commands::issue_commands::change_issue_status,
             commands::issue_commands::add_issue_comment,
             commands::issue_commands::change_issue_subject,
             commands::issue_commands::edit_issue_comment,
             commands::issue_commands::delete_issue_comment,
+            commands::issue_commands::watch_issue,
+            commands::issue_commands::unwatch_issue,
+            commands::issue_commands::upvote_issue,
+            commands::issue_commands::downvote_issue,
+            commands::issue_commands::get_issue_voters,
+            commands::issue_commands::get_issue_custom_attributes,
+            commands::issue_commands::update_issue_custom_attributes,
+            commands::issue_commands::import_issues_from_file,
+            commands::issue_commands::import_issues_from_text,
+            commands::transfer_commands::download_attachment,
+            commands::transfer_commands::upload_attachment_from_path,
+            commands::transfer_commands::get_max_upload_size,
+            commands::transfer_commands::set_max_upload_size,
+            commands::transfer_commands::cancel_transfer,
             commands::draft_commands::save_local_draft,
             commands::draft_commands::get_local_draft,
             commands::draft_commands::delete_local_draft,
@@ -148,6 +214,35 @@ pub fn run() {
             commands::issue_commands::upload_issue_attachment,
             commands::issue_commands::delete_issue_attachment,
             commands::issue_commands::get_issue_attachments,
+            commands::user_story_commands::list_user_stories,
+            commands::user_story_commands::get_user_story_detail,
+            commands::user_story_commands::get_user_story_history,
+            commands::user_story_commands::change_user_story_status,
+            commands::user_story_commands::change_user_story_subject,
+            commands::user_story_commands::change_user_story_assignee,
+            commands::user_story_commands::add_user_story_comment,
+            commands::user_story_commands::update_user_story_tags,
+            commands::user_story_commands::commit_user_story_description,
+            commands::task_commands::list_tasks,
+            commands::task_commands::get_task_detail,
+            commands::task_commands::get_task_history,
+            commands::task_commands::create_task,
+            commands::task_commands::change_task_status,
+            commands::task_commands::change_task_assignee,
+            commands::task_commands::move_task_to_user_story,
+            commands::task_commands::add_task_comment,
+            commands::epic_commands::get_aggregated_epics,
+            commands::epic_commands::list_epic_user_stories,
+            commands::epic_commands::link_user_story_to_epic,
+            commands::epic_commands::unlink_user_story_from_epic,
+            commands::milestone_commands::list_project_milestones,
+            commands::milestone_commands::get_milestone_stats,
+            commands::wiki_commands::list_wiki_pages,
+            commands::wiki_commands::list_wiki_links,
+            commands::wiki_commands::get_wiki_page,
+            commands::wiki_commands::get_wiki_page_by_slug,
+            commands::wiki_commands::create_wiki_page,
+            commands::wiki_commands::commit_wiki_page,
             commands::app_commands::force_close_app,
             commands::view_commands::list_views,
             commands::view_commands::get_view,
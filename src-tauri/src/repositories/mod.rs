@@ -11,6 +11,7 @@ pub trait Repository: Send + Sync {
     async fn save_draft(&self, related_id: &str, draft_type: &str, content: &str) -> Result<()>;
     async fn get_draft(&self, related_id: &str, draft_type: &str) -> Result<Option<String>>;
     async fn delete_draft(&self, related_id: &str, draft_type: &str) -> Result<()>;
+    async fn delete_drafts_for(&self, related_id: &str) -> Result<()>;
 
     // SavedView operations
     async fn list_views(&self) -> Result<Vec<saved_views::Model>>;
@@ -142,6 +143,16 @@ impl Repository for SqliteRepository {
         Ok(())
     }
 
+    async fn delete_drafts_for(&self, related_id: &str) -> Result<()> {
+        drafts::Entity::delete_many()
+            .filter(drafts::Column::RelatedId.eq(related_id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| crate::error::Error::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn list_views(&self) -> Result<Vec<saved_views::Model>> {
         let views = saved_views::Entity::find()
             .all(&self.conn)
@@ -171,7 +182,10 @@ impl Repository for SqliteRepository {
 
         if is_default {
             saved_views::Entity::update_many()
-                .col_expr(saved_views::Column::IsDefault, sea_orm::sea_query::Expr::value(false))
+                .col_expr(
+                    saved_views::Column::IsDefault,
+                    sea_orm::sea_query::Expr::value(false),
+                )
                 .exec(&self.conn)
                 .await
                 .map_err(|e| crate::error::Error::Database(e.to_string()))?;
@@ -233,7 +247,9 @@ impl Repository for SqliteRepository {
 
         if let Some(view_model) = view {
             if view_model.is_system {
-                return Err(crate::error::Error::Database("Cannot delete system view".to_string()));
+                return Err(crate::error::Error::Database(
+                    "Cannot delete system view".to_string(),
+                ));
             }
 
             saved_views::Entity::delete_by_id(id)
@@ -241,7 +257,10 @@ impl Repository for SqliteRepository {
                 .await
                 .map_err(|e| crate::error::Error::Database(e.to_string()))?;
         } else {
-            return Err(crate::error::Error::Database(format!("View with id {} not found", id)));
+            return Err(crate::error::Error::Database(format!(
+                "View with id {} not found",
+                id
+            )));
         }
 
         Ok(())
@@ -19,7 +19,9 @@ async fn create_test_db() -> DatabaseConnection {
     let stmt = builder.build(&stmt);
     conn.execute(stmt).await.unwrap();
 
-    let stmt = schema.create_table_from_entity(saved_views::Entity).to_owned();
+    let stmt = schema
+        .create_table_from_entity(saved_views::Entity)
+        .to_owned();
     let stmt = builder.build(&stmt);
     conn.execute(stmt).await.unwrap();
 
@@ -82,6 +84,34 @@ async fn test_draft_lifecycle() {
     assert_eq!(fetched, None);
 }
 
+#[tokio::test]
+async fn test_delete_drafts_for_related_id() {
+    let conn = create_test_db().await;
+    let repo = SqliteRepository::new(conn);
+
+    repo.save_draft("issue_1", "description", "Desc")
+        .await
+        .unwrap();
+    repo.save_draft("issue_1", "comment", "Comment")
+        .await
+        .unwrap();
+    repo.save_draft("issue_2", "comment", "Other")
+        .await
+        .unwrap();
+
+    repo.delete_drafts_for("issue_1").await.unwrap();
+
+    assert_eq!(
+        repo.get_draft("issue_1", "description").await.unwrap(),
+        None
+    );
+    assert_eq!(repo.get_draft("issue_1", "comment").await.unwrap(), None);
+    assert_eq!(
+        repo.get_draft("issue_2", "comment").await.unwrap(),
+        Some("Other".to_string())
+    );
+}
+
 #[tokio::test]
 async fn test_create_view() {
     let conn = create_test_db().await;
@@ -173,10 +203,7 @@ async fn test_touch_view() {
     let conn = create_test_db().await;
     let repo = SqliteRepository::new(conn);
 
-    let view = repo
-        .create_view("View", "{}", false, false)
-        .await
-        .unwrap();
+    let view = repo.create_view("View", "{}", false, false).await.unwrap();
     let original_last_used = view.last_used;
 
     tokio::time::sleep(std::time::Duration::from_millis(10)).await;
@@ -192,10 +219,7 @@ async fn test_set_default_view() {
     let conn = create_test_db().await;
     let repo = SqliteRepository::new(conn);
 
-    let view1 = repo
-        .create_view("View 1", "{}", false, true)
-        .await
-        .unwrap();
+    let view1 = repo.create_view("View 1", "{}", false, true).await.unwrap();
     let view2 = repo
         .create_view("View 2", "{}", false, false)
         .await
@@ -0,0 +1,537 @@
+use std::collections::HashMap;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::error::{Error, Result};
+use crate::repositories::Repository;
+use crate::services::auth_client::AuthClient;
+use crate::services::connection_settings::ConnectionSettings;
+use crate::services::credential_store::CredentialVault;
+use crate::services::credentials::{self, CredentialKey};
+use serde::{Deserialize, Serialize};
+use taiga_client::TaigaClient;
+use url::Url;
+
+/// Config key holding the accounts and the active one as JSON
+pub const ACCOUNTS_KEY: &str = "accounts";
+
+/// Account created for the session saved before Larch supported several accounts
+pub const LEGACY_ACCOUNT_ID: &str = "default";
+
+/// Config keys of the single-account setup, read once to create the legacy account
+const LEGACY_API_URL_KEY: &str = "taiga_api_url";
+const LEGACY_SELECTED_PROJECTS_KEY: &str = "selected_projects";
+
+/// A named sign-in to one Taiga instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Account {
+    /// Stable key for keyring entries and commands, derived from the first name
+    pub id: String,
+    pub name: String,
+    pub api_url: String,
+    pub username: Option<String>,
+    /// Projects this account contributes to the aggregated dashboard
+    #[serde(default)]
+    pub selected_projects: Vec<i64>,
+}
+
+impl Account {
+    /// Key of the account's tokens in the credential store; `Unauthorized` until someone signs in
+    pub fn credential_key(&self) -> Result<CredentialKey> {
+        let username = self.username.as_deref().ok_or(Error::Unauthorized)?;
+        CredentialKey::new(&self.api_url, username)
+    }
+
+    /// Whether signing in as `username` at `api_url` means this account
+    /// The legacy account has no username yet and is claimed by any user of its server.
+    fn matches_login(&self, api_url: &str, username: &str) -> bool {
+        let username = self.username.as_deref().unwrap_or(username);
+        match (
+            CredentialKey::new(&self.api_url, username),
+            CredentialKey::new(api_url, username),
+        ) {
+            (Ok(own), Ok(login)) => own == login,
+            _ => false,
+        }
+    }
+}
+
+/// What the account switcher shows for each account
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountSummary {
+    pub id: String,
+    pub name: String,
+    pub api_url: String,
+    pub username: Option<String>,
+    pub active: bool,
+    pub signed_in: bool,
+}
+
+/// All configured accounts and the one commands act on
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountStore {
+    pub accounts: Vec<Account>,
+    pub active: Option<String>,
+}
+
+impl AccountStore {
+    /// Stored accounts, or the legacy single-account config turned into one
+    pub async fn load<R: Repository>(repo: &R) -> Result<Self> {
+        match repo.get_config(ACCOUNTS_KEY).await? {
+            Some(value) => Ok(serde_json::from_str(&value)?),
+            None => Self::from_legacy_config(repo).await,
+        }
+    }
+
+    pub async fn save<R: Repository>(&self, repo: &R) -> Result<()> {
+        let value = serde_json::to_string(self)?;
+        repo.save_config(ACCOUNTS_KEY, &value).await
+    }
+
+    async fn from_legacy_config<R: Repository>(repo: &R) -> Result<Self> {
+        let Some(api_url) = repo.get_config(LEGACY_API_URL_KEY).await? else {
+            return Ok(Self::default());
+        };
+        let selected_projects = repo
+            .get_config(LEGACY_SELECTED_PROJECTS_KEY)
+            .await?
+            .and_then(|value| serde_json::from_str(&value).ok())
+            .unwrap_or_default();
+
+        log::info!("Creating account '{}' for {}", LEGACY_ACCOUNT_ID, api_url);
+        Ok(Self {
+            accounts: vec![Account {
+                id: LEGACY_ACCOUNT_ID.to_string(),
+                name: default_account_name(&api_url),
+                api_url,
+                username: None,
+                selected_projects,
+            }],
+            active: Some(LEGACY_ACCOUNT_ID.to_string()),
+        })
+    }
+
+    pub fn get(&self, account_id: &str) -> Result<&Account> {
+        self.accounts
+            .iter()
+            .find(|a| a.id == account_id)
+            .ok_or_else(|| Error::InvalidInput(format!("Account '{}' not found", account_id)))
+    }
+
+    pub fn get_mut(&mut self, account_id: &str) -> Result<&mut Account> {
+        self.accounts
+            .iter_mut()
+            .find(|a| a.id == account_id)
+            .ok_or_else(|| Error::InvalidInput(format!("Account '{}' not found", account_id)))
+    }
+
+    pub fn active_account(&self) -> Result<&Account> {
+        let account_id = self.active.as_deref().ok_or(Error::Unauthorized)?;
+        self.get(account_id)
+    }
+
+    pub fn active_account_mut(&mut self) -> Result<&mut Account> {
+        let account_id = self.active.clone().ok_or(Error::Unauthorized)?;
+        self.get_mut(&account_id)
+    }
+
+    /// Record a login and make its account active; returns the account ID
+    /// Signing in again as the same user on the same server reuses the account.
+    pub fn sign_in(&mut self, name: Option<&str>, api_url: &str, username: &str) -> String {
+        let existing = self
+            .accounts
+            .iter_mut()
+            .find(|a| a.matches_login(api_url, username));
+
+        let account_id = match existing {
+            Some(account) => {
+                account.username = Some(username.to_string());
+                if let Some(name) = non_empty(name) {
+                    account.name = name.to_string();
+                }
+                account.id.clone()
+            }
+            None => {
+                let name = non_empty(name)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| default_account_name(api_url));
+                let id = self.unique_id(&name);
+                self.accounts.push(Account {
+                    id: id.clone(),
+                    name,
+                    api_url: api_url.to_string(),
+                    username: Some(username.to_string()),
+                    selected_projects: Vec::new(),
+                });
+                id
+            }
+        };
+
+        self.active = Some(account_id.clone());
+        account_id
+    }
+
+    /// Forget an account; another one becomes active if it was the active one
+    pub fn remove(&mut self, account_id: &str) -> Result<Account> {
+        let index = self
+            .accounts
+            .iter()
+            .position(|a| a.id == account_id)
+            .ok_or_else(|| Error::InvalidInput(format!("Account '{}' not found", account_id)))?;
+        let removed = self.accounts.remove(index);
+
+        if self.active.as_deref() == Some(account_id) {
+            self.active = self.accounts.first().map(|a| a.id.clone());
+        }
+        Ok(removed)
+    }
+
+    /// Lowercase slug of `name`, suffixed with a number when already taken
+    fn unique_id(&self, name: &str) -> String {
+        let slug = name
+            .trim()
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let slug = if slug.is_empty() {
+            "account".to_string()
+        } else {
+            slug
+        };
+
+        let taken = |id: &str| self.accounts.iter().any(|a| a.id == id);
+        if !taken(&slug) {
+            return slug;
+        }
+        (2..)
+            .map(|n| format!("{}-{}", slug, n))
+            .find(|id| !taken(id))
+            .expect("unbounded range always yields a free ID")
+    }
+}
+
+/// Host of the API URL, e.g. `api.taiga.io`
+pub fn default_account_name(api_url: &str) -> String {
+    Url::parse(api_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| api_url.to_string())
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Clients of the signed-in accounts, keyed by account ID
+/// Commands act on the active account unless they are told otherwise.
+#[derive(Default)]
+pub struct AccountClients {
+    clients: RwLock<HashMap<String, AuthClient>>,
+    active: RwLock<Option<String>>,
+}
+
+impl AccountClients {
+    pub fn insert(&self, account_id: &str, client: AuthClient) {
+        self.write_clients().insert(account_id.to_string(), client);
+    }
+
+    pub fn remove(&self, account_id: &str) {
+        self.write_clients().remove(account_id);
+    }
+
+    pub fn set_active(&self, account_id: Option<String>) {
+        *self.active.write().unwrap_or_else(|e| e.into_inner()) = account_id;
+    }
+
+    pub fn active_id(&self) -> Option<String> {
+        self.active
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Client of the active account; `Unauthorized` when it is signed out
+    pub fn active(&self) -> Result<AuthClient> {
+        let account_id = self.active_id().ok_or(Error::Unauthorized)?;
+        self.get(&account_id)
+    }
+
+    /// Client of `account_id`, or of the active account when none is given
+    pub fn resolve(&self, account_id: Option<&str>) -> Result<AuthClient> {
+        match account_id {
+            Some(account_id) => self.get(account_id),
+            None => self.active(),
+        }
+    }
+
+    pub fn get(&self, account_id: &str) -> Result<AuthClient> {
+        self.read_clients()
+            .get(account_id)
+            .cloned()
+            .ok_or(Error::Unauthorized)
+    }
+
+    pub fn is_signed_in(&self, account_id: &str) -> bool {
+        self.read_clients().contains_key(account_id)
+    }
+
+    /// The signed-in account whose Taiga instance serves `url`
+    pub fn for_url(&self, url: &str) -> Option<AuthClient> {
+        let clients = self.read_clients();
+        let active = self.active_id();
+
+        // Prefer the active account when several accounts share an instance
+        active
+            .as_ref()
+            .and_then(|id| clients.get_key_value(id))
+            .filter(|(_, client)| client.client().is_managed_url(url))
+            .or_else(|| {
+                clients
+                    .iter()
+                    .find(|(_, client)| client.client().is_managed_url(url))
+            })
+            .map(|(_, client)| client.clone())
+    }
+
+    fn read_clients(&self) -> RwLockReadGuard<'_, HashMap<String, AuthClient>> {
+        // A panic while holding the lock cannot leave the map inconsistent
+        self.clients.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_clients(&self) -> RwLockWriteGuard<'_, HashMap<String, AuthClient>> {
+        self.clients.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Build a client for every stored account and activate the saved active one
+/// Also persists the account created from a legacy single-account config and
+/// moves keyring entries written by earlier versions to their current names.
+/// Tokens are read from `vault` on demand, so a locked credential file only
+/// has to be unlocked before the first call. Nothing here touches the network:
+/// a legacy account without a username uses its legacy entries until
+/// `complete_sessions` looks the username up.
+pub async fn restore_sessions<R: Repository>(
+    repo: &R,
+    vault: &CredentialVault,
+) -> Result<AccountClients> {
+    let store = AccountStore::load(repo).await?;
+    let settings = ConnectionSettings::load(repo).await?;
+    let clients = AccountClients::default();
+    for account in &store.accounts {
+        if account.username.is_none() {
+            if let Some(client) = taiga_client(&settings, account) {
+                clients.insert(
+                    &account.id,
+                    AuthClient::legacy(client, vault.clone(), &account.id),
+                );
+                log::info!(
+                    "Restored legacy Taiga session '{}' for {}",
+                    account.id,
+                    account.api_url
+                );
+            }
+            continue;
+        }
+        if let Some((client, key)) = account_client(&settings, account) {
+            if !vault.is_locked() {
+                migrate_legacy_entries(vault, &account.id, &key);
+            }
+            clients.insert(&account.id, AuthClient::new(client, vault.clone(), key));
+            log::info!(
+                "Restored Taiga session '{}' for {}",
+                account.id,
+                account.api_url
+            );
+        }
+    }
+
+    if repo.get_config(ACCOUNTS_KEY).await?.is_none() && !store.accounts.is_empty() {
+        store.save(repo).await?;
+    }
+    clients.set_active(store.active);
+
+    Ok(clients)
+}
+
+/// Finish what `restore_sessions` leaves to later: ask Taiga for the username
+/// behind a legacy account's token, then move its entries and switch it to a
+/// regular client. Needs an unlocked vault, so it runs after startup and again
+/// after the credential file is unlocked; a failed lookup is retried on the next
+/// run, and the legacy session keeps working meanwhile.
+pub async fn complete_sessions<R: Repository>(
+    repo: &R,
+    vault: &CredentialVault,
+    clients: &AccountClients,
+) -> Result<()> {
+    if vault.is_locked() {
+        return Ok(());
+    }
+
+    let settings = ConnectionSettings::load(repo).await?;
+    let mut found = Vec::new();
+    for account in AccountStore::load(repo).await?.accounts {
+        if account.username.is_some() {
+            if let Ok(key) = account.credential_key() {
+                migrate_legacy_entries(vault, &account.id, &key);
+            }
+            continue;
+        }
+        let Some(client) = taiga_client(&settings, &account) else {
+            continue;
+        };
+        match legacy_username(&client, vault, &account.id).await {
+            Ok(username) => found.push((account.id, username)),
+            Err(e) => log::warn!(
+                "Account '{}' keeps its legacy session; its username is unknown: {}",
+                account.id,
+                e
+            ),
+        }
+    }
+    if found.is_empty() {
+        return Ok(());
+    }
+
+    // Reload so changes made while Taiga was asked are kept
+    let mut store = AccountStore::load(repo).await?;
+    let mut changed = false;
+    for (account_id, username) in found {
+        let Ok(account) = store.get_mut(&account_id) else {
+            continue;
+        };
+        if account.username.is_some() {
+            continue;
+        }
+        account.username = Some(username);
+        changed = true;
+        if let Some((client, key)) = account_client(&settings, account) {
+            migrate_legacy_entries(vault, &account.id, &key);
+            clients.insert(&account.id, AuthClient::new(client, vault.clone(), key));
+            log::info!(
+                "Restored Taiga session '{}' for {}",
+                account.id,
+                account.api_url
+            );
+        }
+    }
+    if changed {
+        store.save(repo).await?;
+    }
+
+    Ok(())
+}
+
+/// Client and credential key of a signed-in account; `None` after logging why not
+fn account_client(
+    settings: &ConnectionSettings,
+    account: &Account,
+) -> Option<(TaigaClient, CredentialKey)> {
+    let client = taiga_client(settings, account)?;
+    match account.credential_key() {
+        Ok(key) => Some((client, key)),
+        Err(e) => {
+            log::error!("Invalid credentials of account '{}': {}", account.id, e);
+            None
+        }
+    }
+}
+
+fn taiga_client(settings: &ConnectionSettings, account: &Account) -> Option<TaigaClient> {
+    let url = match account.api_url.parse::<Url>() {
+        Ok(url) => url,
+        Err(_) => {
+            log::error!(
+                "Failed to parse API URL of account '{}': {}",
+                account.id,
+                account.api_url
+            );
+            return None;
+        }
+    };
+    Some(settings.build_client(url.clone()).unwrap_or_else(|e| {
+        log::error!("Ignoring connection settings: {}", e);
+        TaigaClient::new(url)
+    }))
+}
+
+fn migrate_legacy_entries(vault: &CredentialVault, account_id: &str, key: &CredentialKey) {
+    match credentials::migrate_legacy_entries(vault, account_id, key) {
+        Ok(true) => log::info!("Moved keyring entries of account '{}'", account_id),
+        Ok(false) => {}
+        Err(e) => log::error!(
+            "Failed to move keyring entries of account '{}': {}",
+            account_id,
+            e
+        ),
+    }
+}
+
+/// Username behind the token a legacy account left in the keyring
+async fn legacy_username(
+    client: &TaigaClient,
+    vault: &CredentialVault,
+    account_id: &str,
+) -> Result<String> {
+    let token = credentials::get_legacy_api_token(vault, account_id)?;
+    Ok(client.get_me(&token).await?.username)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_in_reuses_account_for_same_user_and_server() {
+        let mut store = AccountStore::default();
+
+        let cloud = store.sign_in(None, "https://api.taiga.io/", "ada");
+        let work = store.sign_in(Some("Work"), "https://taiga.example.com/api/v1", "ada");
+        let again = store.sign_in(None, "https://api.taiga.io/", "ADA");
+
+        assert_eq!(cloud, "api-taiga-io");
+        assert_eq!(work, "work");
+        assert_eq!(again, cloud);
+        assert_eq!(store.accounts.len(), 2);
+        assert_eq!(store.active.as_deref(), Some("api-taiga-io"));
+    }
+
+    #[test]
+    fn test_sign_in_claims_legacy_account_and_dedupes_ids() {
+        let mut store = AccountStore {
+            accounts: vec![Account {
+                id: LEGACY_ACCOUNT_ID.to_string(),
+                name: "api.taiga.io".to_string(),
+                api_url: "https://api.taiga.io/".to_string(),
+                username: None,
+                selected_projects: vec![1, 2],
+            }],
+            active: None,
+        };
+
+        assert_eq!(
+            store.sign_in(None, "https://api.taiga.io/", "ada"),
+            LEGACY_ACCOUNT_ID
+        );
+        assert_eq!(
+            store.sign_in(Some("Default"), "https://other.example/api/v1", "bob"),
+            "default-2"
+        );
+    }
+
+    #[test]
+    fn test_remove_active_account_activates_another() {
+        let mut store = AccountStore::default();
+        store.sign_in(Some("Cloud"), "https://api.taiga.io/", "ada");
+        store.sign_in(Some("Work"), "https://taiga.example.com/api/v1", "ada");
+
+        store.remove("work").unwrap();
+
+        assert_eq!(store.active.as_deref(), Some("cloud"));
+        assert!(store.remove("work").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+use crate::services::credential_store::CredentialVault;
+use crate::services::credentials::{self, CredentialKey};
+use secrecy::{ExposeSecret, Secret};
+use taiga_client::TaigaClient;
+use tauri::async_runtime::Mutex;
+
+/// Source of the API token, and the way to renew it once Taiga rejects it
+#[async_trait::async_trait]
+pub trait TokenProvider: Send + Sync {
+    fn api_token(&self) -> Result<Secret<String>>;
+
+    /// Exchange the refresh token for a new token pair and store both
+    async fn refresh(&self, client: &TaigaClient) -> Result<()>;
+}
+
+/// Tokens of one account, read from the selected credential store on every call
+pub struct StoredTokenProvider {
+    store: CredentialVault,
+    key: CredentialKey,
+}
+
+#[async_trait::async_trait]
+impl TokenProvider for StoredTokenProvider {
+    fn api_token(&self) -> Result<Secret<String>> {
+        credentials::get_api_token(&self.store, &self.key)
+    }
+
+    async fn refresh(&self, client: &TaigaClient) -> Result<()> {
+        let refresh = credentials::get_refresh_token(&self.store, &self.key)?;
+        let new_tokens = client.refresh_token(refresh.expose_secret()).await?;
+
+        // Persist refresh token first to avoid stale refresh token if set_api_token succeeds but set_refresh_token fails
+        credentials::set_refresh_token(&self.store, &self.key, &new_tokens.refresh)?;
+        credentials::set_api_token(&self.store, &self.key, &new_tokens.auth_token)?;
+
+        log::info!("Token refreshed successfully");
+        Ok(())
+    }
+}
+
+/// Tokens of the legacy account, still under the names used before accounts had usernames
+/// Serves its session until `complete_sessions` learns the username and moves them.
+pub struct LegacyTokenProvider {
+    store: CredentialVault,
+    account_id: String,
+}
+
+#[async_trait::async_trait]
+impl TokenProvider for LegacyTokenProvider {
+    fn api_token(&self) -> Result<Secret<String>> {
+        credentials::get_legacy_api_token(&self.store, &self.account_id)
+    }
+
+    async fn refresh(&self, client: &TaigaClient) -> Result<()> {
+        let refresh = credentials::get_legacy_refresh_token(&self.store, &self.account_id)?;
+        let new_tokens = client.refresh_token(refresh.expose_secret()).await?;
+        credentials::set_legacy_tokens(
+            &self.store,
+            &self.account_id,
+            &new_tokens.auth_token,
+            &new_tokens.refresh,
+        )?;
+
+        log::info!("Token refreshed successfully");
+        Ok(())
+    }
+}
+
+/// `TaigaClient` that supplies the API token and renews it when Taiga answers 401
+/// Refreshes are serialized, so calls failing at the same time rotate the
+/// refresh token only once and then replay with the new token.
+#[derive(Clone)]
+pub struct AuthClient {
+    client: TaigaClient,
+    tokens: Arc<dyn TokenProvider>,
+    refresh_lock: Arc<Mutex<()>>,
+}
+
+impl AuthClient {
+    /// Client for an account whose tokens live in `store` under `key`
+    pub fn new(client: TaigaClient, store: CredentialVault, key: CredentialKey) -> Self {
+        Self::with_provider(client, Arc::new(StoredTokenProvider { store, key }))
+    }
+
+    /// Client for the legacy account, whose username is not known yet
+    pub fn legacy(client: TaigaClient, store: CredentialVault, account_id: &str) -> Self {
+        Self::with_provider(
+            client,
+            Arc::new(LegacyTokenProvider {
+                store,
+                account_id: account_id.to_string(),
+            }),
+        )
+    }
+
+    pub fn with_provider(client: TaigaClient, tokens: Arc<dyn TokenProvider>) -> Self {
+        Self {
+            client,
+            tokens,
+            refresh_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// The underlying client, for calls that need no token
+    pub fn client(&self) -> &TaigaClient {
+        &self.client
+    }
+
+    /// Current API token, for requests that may also go out without one
+    pub fn api_token(&self) -> Result<Secret<String>> {
+        self.tokens.api_token()
+    }
+
+    /// Run `op` with the current token; on `Unauthorized`, refresh once and run it again
+    /// `op` may be called twice, so it must be safe to replay after a 401.
+    pub async fn call<'a, T, F, Fut>(&'a self, op: F) -> Result<T>
+    where
+        F: Fn(&'a TaigaClient, Secret<String>) -> Fut,
+        Fut: Future<Output = Result<T>> + 'a,
+    {
+        let token = self.tokens.api_token()?;
+        match op(&self.client, token.clone()).await {
+            Err(Error::Unauthorized) => {
+                self.refresh_rejected(&token).await?;
+                op(&self.client, self.tokens.api_token()?).await
+            }
+            result => result,
+        }
+    }
+
+    /// Refresh the token pair unconditionally
+    pub async fn refresh(&self) -> Result<()> {
+        let _guard = self.refresh_lock.lock().await;
+        self.tokens.refresh(&self.client).await
+    }
+
+    async fn refresh_rejected(&self, rejected: &Secret<String>) -> Result<()> {
+        let _guard = self.refresh_lock.lock().await;
+
+        // Another call may have refreshed while this one waited for the lock
+        if let Ok(current) = self.tokens.api_token() {
+            if current.expose_secret() != rejected.expose_secret() {
+                return Ok(());
+            }
+        }
+
+        log::info!("Unauthorized, attempting token refresh");
+        self.tokens.refresh(&self.client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex as StdMutex;
+
+    /// Hands out `token-N`, where N counts the refreshes so far
+    struct CountingProvider {
+        refreshes: AtomicUsize,
+        token: StdMutex<String>,
+    }
+
+    impl CountingProvider {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                refreshes: AtomicUsize::new(0),
+                token: StdMutex::new("token-0".to_string()),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TokenProvider for CountingProvider {
+        fn api_token(&self) -> Result<Secret<String>> {
+            Ok(Secret::new(self.token.lock().unwrap().clone()))
+        }
+
+        async fn refresh(&self, _client: &TaigaClient) -> Result<()> {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            let n = self.refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+            *self.token.lock().unwrap() = format!("token-{}", n);
+            Ok(())
+        }
+    }
+
+    fn auth_client(provider: Arc<CountingProvider>) -> AuthClient {
+        let client = TaigaClient::new("https://api.taiga.io/".parse().unwrap());
+        AuthClient::with_provider(client, provider)
+    }
+
+    /// Accepts only tokens issued after the first refresh
+    async fn protected(token: Secret<String>) -> Result<String> {
+        match token.expose_secret().as_str() {
+            "token-0" => Err(Error::Unauthorized),
+            other => Ok(other.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_refreshes_and_replays_after_unauthorized() {
+        let provider = CountingProvider::new();
+        let auth = auth_client(Arc::clone(&provider));
+
+        let result = auth.call(|_, token| protected(token)).await.unwrap();
+
+        assert_eq!(result, "token-1");
+        assert_eq!(provider.refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_unauthorized_calls_refresh_once() {
+        let provider = CountingProvider::new();
+        let auth = auth_client(Arc::clone(&provider));
+
+        let (a, b, c) = tokio::join!(
+            auth.call(|_, token| protected(token)),
+            auth.call(|_, token| protected(token)),
+            auth.call(|_, token| protected(token)),
+        );
+
+        assert_eq!(a.unwrap(), "token-1");
+        assert_eq!(b.unwrap(), "token-1");
+        assert_eq!(c.unwrap(), "token-1");
+        assert_eq!(provider.refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_gives_up_after_one_refresh() {
+        let provider = CountingProvider::new();
+        let auth = auth_client(Arc::clone(&provider));
+
+        let result: Result<()> = auth.call(|_, _| async { Err(Error::Unauthorized) }).await;
+
+        assert!(matches!(result, Err(Error::Unauthorized)));
+        assert_eq!(provider.refreshes.load(Ordering::SeqCst), 1);
+    }
+}
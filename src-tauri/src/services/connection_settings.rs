@@ -0,0 +1,128 @@
+use crate::error::{Error, Result};
+use crate::repositories::Repository;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use taiga_client::TaigaClient;
+use url::Url;
+
+/// Config key holding the connection settings as JSON
+pub const CONNECTION_SETTINGS_KEY: &str = "connection_settings";
+
+const DEFAULT_USER_AGENT: &str = concat!("Larch/", env!("CARGO_PKG_VERSION"));
+
+/// HTTP settings applied whenever Larch creates a `TaigaClient`
+/// Unset values keep the client defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectionSettings {
+    pub connect_timeout_secs: Option<u64>,
+    pub read_timeout_secs: Option<u64>,
+    /// e.g. `http://proxy.corp.example:3128`
+    pub proxy_url: Option<String>,
+    /// PEM bundles trusted in addition to the system roots
+    pub root_certificates_pem: Vec<String>,
+    /// Skip TLS certificate validation; only for local development instances
+    pub accept_invalid_certs: bool,
+    pub user_agent: Option<String>,
+    /// Language for Taiga's translated names and messages, e.g. `de`
+    pub accept_language: Option<String>,
+}
+
+impl ConnectionSettings {
+    pub async fn load<R: Repository>(repo: &R) -> Result<Self> {
+        let value = repo.get_config(CONNECTION_SETTINGS_KEY).await?;
+        Ok(Self::parse(value.as_deref()))
+    }
+
+    pub async fn save<R: Repository>(&self, repo: &R) -> Result<()> {
+        let value = serde_json::to_string(self)?;
+        repo.save_config(CONNECTION_SETTINGS_KEY, &value).await
+    }
+
+    /// Stored settings, or the defaults when unset or unreadable
+    fn parse(value: Option<&str>) -> Self {
+        match value.map(serde_json::from_str::<Self>) {
+            Some(Ok(settings)) => settings,
+            Some(Err(e)) => {
+                log::warn!("Ignoring invalid connection settings: {}", e);
+                Self::default()
+            }
+            None => Self::default(),
+        }
+    }
+
+    pub fn build_client(&self, api_base_url: Url) -> Result<TaigaClient> {
+        let mut builder = TaigaClient::builder(api_base_url).user_agent(
+            self.user_agent
+                .as_deref()
+                .filter(|ua| !ua.trim().is_empty())
+                .unwrap_or(DEFAULT_USER_AGENT),
+        );
+
+        if let Some(secs) = self.connect_timeout_secs {
+            builder = builder.connect_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = self.read_timeout_secs {
+            builder = builder.read_timeout(Duration::from_secs(secs));
+        }
+        if let Some(proxy_url) = non_empty(&self.proxy_url) {
+            builder = builder.proxy(proxy_url);
+        }
+        for pem in &self.root_certificates_pem {
+            builder = builder.add_root_certificates_pem(pem.as_bytes());
+        }
+        if let Some(language) = non_empty(&self.accept_language) {
+            builder = builder.accept_language(language);
+        }
+
+        builder
+            .danger_accept_invalid_certs(self.accept_invalid_certs)
+            .build()
+            .map_err(|e| Error::InvalidInput(e.to_string()))
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_defaults_for_missing_or_invalid_values() {
+        assert_eq!(
+            ConnectionSettings::parse(None),
+            ConnectionSettings::default()
+        );
+        assert_eq!(
+            ConnectionSettings::parse(Some("not json")),
+            ConnectionSettings::default()
+        );
+
+        let settings = ConnectionSettings::parse(Some(r#"{"read_timeout_secs": 60}"#));
+        assert_eq!(settings.read_timeout_secs, Some(60));
+        assert_eq!(settings.proxy_url, None);
+    }
+
+    #[test]
+    fn test_build_client_reports_invalid_settings() {
+        let url = Url::parse("https://api.taiga.io/").unwrap();
+        let settings = ConnectionSettings {
+            proxy_url: Some("http://proxy.corp.example:3128".to_string()),
+            accept_language: Some("de".to_string()),
+            ..ConnectionSettings::default()
+        };
+        assert!(settings.build_client(url.clone()).is_ok());
+
+        let settings = ConnectionSettings {
+            root_certificates_pem: vec!["not a certificate".to_string()],
+            ..ConnectionSettings::default()
+        };
+        assert!(matches!(
+            settings.build_client(url),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,502 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::error::{Error, Result};
+use crate::repositories::Repository;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use keyring::Entry;
+use secrecy::Secret;
+use serde::{Deserialize, Serialize};
+
+/// Config key holding the chosen `CredentialBackend` as JSON
+pub const CREDENTIAL_BACKEND_KEY: &str = "credential_backend";
+
+/// Name of the encrypted credential file inside the app data dir
+pub const CREDENTIAL_FILE_NAME: &str = "credentials.enc";
+
+const SERVICE_NAME: &str = "larch-app";
+const PROBE_ENTRY: &str = "larch-keyring-probe";
+
+const FILE_MAGIC: &[u8; 8] = b"LARCHCS1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// Place where secrets are kept under an entry name
+pub trait CredentialStore: Send + Sync {
+    /// The secret stored under `entry`, or `None` if there is none
+    fn get(&self, entry: &str) -> Result<Option<Secret<String>>>;
+
+    fn set(&self, entry: &str, secret: &str) -> Result<()>;
+
+    /// Remove `entry`; removing a missing entry is not an error
+    fn delete(&self, entry: &str) -> Result<()>;
+}
+
+/// Where Larch keeps its tokens
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialBackend {
+    /// The OS credential manager (Keychain, Credential Manager, Secret Service)
+    #[default]
+    Keyring,
+    /// A file in the app data dir, encrypted with a key derived from a passphrase
+    EncryptedFile,
+}
+
+/// Secrets in the OS keyring via `keyring-rs`
+#[derive(Debug, Default)]
+pub struct KeyringStore;
+
+impl KeyringStore {
+    /// Check that the keyring answers at all
+    /// Fails on Linux desktops without a Secret Service, where every entry fails.
+    pub fn probe() -> Result<()> {
+        match Entry::new(SERVICE_NAME, PROBE_ENTRY)?.get_password() {
+            Ok(_) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl CredentialStore for KeyringStore {
+    fn get(&self, entry: &str) -> Result<Option<Secret<String>>> {
+        match Entry::new(SERVICE_NAME, entry)?.get_password() {
+            Ok(secret) => Ok(Some(Secret::new(secret))),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn set(&self, entry: &str, secret: &str) -> Result<()> {
+        Entry::new(SERVICE_NAME, entry)?.set_password(secret)?;
+        Ok(())
+    }
+
+    fn delete(&self, entry: &str) -> Result<()> {
+        match Entry::new(SERVICE_NAME, entry)?.delete_password() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Secrets in one file, for systems without a usable keyring
+/// The file holds a random salt, then the entries as JSON sealed with
+/// XChaCha20-Poly1305 under a key derived from the passphrase with Argon2id.
+/// It is rewritten with a fresh nonce on every change; secrets are only
+/// decrypted while an entry is read or written.
+pub struct EncryptedFileStore {
+    path: PathBuf,
+    salt: [u8; SALT_LEN],
+    cipher: XChaCha20Poly1305,
+    write_lock: Mutex<()>,
+}
+
+impl EncryptedFileStore {
+    /// Unlock the file at `path`, creating an empty one if it does not exist yet
+    /// A wrong passphrase is rejected with `InvalidInput`.
+    pub fn open(path: PathBuf, passphrase: &str) -> Result<Self> {
+        if passphrase.is_empty() {
+            return Err(Error::InvalidInput(
+                "Passphrase cannot be empty".to_string(),
+            ));
+        }
+
+        match fs::read(&path) {
+            Ok(data) => {
+                let salt = parse_file(&data)?.0;
+                let store = Self::with_passphrase(path, salt, passphrase)?;
+                store.decrypt(&data).map_err(|_| {
+                    Error::InvalidInput(
+                        "Wrong passphrase, or the credential file is damaged".to_string(),
+                    )
+                })?;
+                Ok(store)
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                let mut salt = [0u8; SALT_LEN];
+                OsRng.fill_bytes(&mut salt);
+                let store = Self::with_passphrase(path, salt, passphrase)?;
+                store.write_entries(&BTreeMap::new())?;
+                log::info!("Created credential file {}", store.path.display());
+                Ok(store)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn with_passphrase(path: PathBuf, salt: [u8; SALT_LEN], passphrase: &str) -> Result<Self> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|e| Error::CredentialStore(format!("Failed to derive the key: {}", e)))?;
+        let cipher = XChaCha20Poly1305::new(&key.into());
+        key.fill(0);
+
+        Ok(Self {
+            path,
+            salt,
+            cipher,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// Magic and salt; authenticated along with the entries
+    fn header(&self) -> Vec<u8> {
+        [FILE_MAGIC.as_slice(), &self.salt].concat()
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<BTreeMap<String, String>> {
+        let (salt, nonce, ciphertext) = parse_file(data)?;
+        if salt != self.salt {
+            return Err(Error::CredentialStore(
+                "The credential file was replaced; unlock it again".to_string(),
+            ));
+        }
+
+        let plaintext = self
+            .cipher
+            .decrypt(
+                XNonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &self.header(),
+                },
+            )
+            .map_err(|_| Error::CredentialStore("Failed to decrypt the credential file".into()))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    fn read_entries(&self) -> Result<BTreeMap<String, String>> {
+        match fs::read(&self.path) {
+            Ok(data) => self.decrypt(&data),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replace the file atomically, readable by the current user only
+    fn write_entries(&self, entries: &BTreeMap<String, String>) -> Result<()> {
+        let plaintext = serde_json::to_vec(entries)?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &plaintext,
+                    aad: &self.header(),
+                },
+            )
+            .map_err(|_| Error::CredentialStore("Failed to encrypt the credential file".into()))?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&tmp_path)?;
+        file.write_all(&self.header())?;
+        file.write_all(&nonce)?;
+        file.write_all(&ciphertext)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn update(&self, change: impl FnOnce(&mut BTreeMap<String, String>) -> bool) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut entries = self.read_entries()?;
+        if change(&mut entries) {
+            self.write_entries(&entries)?;
+        }
+        Ok(())
+    }
+}
+
+impl CredentialStore for EncryptedFileStore {
+    fn get(&self, entry: &str) -> Result<Option<Secret<String>>> {
+        Ok(self.read_entries()?.remove(entry).map(Secret::new))
+    }
+
+    fn set(&self, entry: &str, secret: &str) -> Result<()> {
+        self.update(|entries| {
+            entries.insert(entry.to_string(), secret.to_string());
+            true
+        })
+    }
+
+    fn delete(&self, entry: &str) -> Result<()> {
+        self.update(|entries| entries.remove(entry).is_some())
+    }
+}
+
+/// Salt, nonce and ciphertext of a credential file
+fn parse_file(data: &[u8]) -> Result<([u8; SALT_LEN], &[u8], &[u8])> {
+    let body = data
+        .strip_prefix(FILE_MAGIC.as_slice())
+        .filter(|body| body.len() > SALT_LEN + NONCE_LEN)
+        .ok_or_else(|| Error::CredentialStore("Not a Larch credential file".to_string()))?;
+
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let mut salt_bytes = [0u8; SALT_LEN];
+    salt_bytes.copy_from_slice(salt);
+    Ok((salt_bytes, nonce, ciphertext))
+}
+
+/// What the settings screen shows about credential storage
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialStoreStatus {
+    pub backend: CredentialBackend,
+    /// Whether the OS keyring answered at startup; if not, offer the encrypted file
+    pub keyring_available: bool,
+    /// The encrypted file is selected but its passphrase was not entered yet
+    pub locked: bool,
+}
+
+struct VaultState {
+    backend: CredentialBackend,
+    store: Option<Arc<dyn CredentialStore>>,
+    keyring_available: bool,
+}
+
+/// The credential store the user picked, shared by every command and `AuthClient`
+/// Clones share one state, so switching or unlocking the backend reaches all
+/// of them. While the encrypted file is locked every access fails with
+/// `CredentialsLocked`.
+#[derive(Clone)]
+pub struct CredentialVault {
+    state: Arc<RwLock<VaultState>>,
+    file_path: Arc<PathBuf>,
+}
+
+impl CredentialVault {
+    /// Vault for the saved backend, with the credential file in `data_dir`
+    /// The encrypted file starts locked. The keyring is probed so that a
+    /// missing Secret Service is reported before the first login fails.
+    pub async fn load<R: Repository>(repo: &R, data_dir: &Path) -> Result<Self> {
+        let backend = match repo.get_config(CREDENTIAL_BACKEND_KEY).await? {
+            Some(value) => serde_json::from_str(&value).unwrap_or_else(|e| {
+                log::warn!("Ignoring invalid credential backend: {}", e);
+                CredentialBackend::default()
+            }),
+            None => CredentialBackend::default(),
+        };
+
+        let keyring_available = match KeyringStore::probe() {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("The OS keyring is unavailable: {}", e);
+                false
+            }
+        };
+
+        let store: Option<Arc<dyn CredentialStore>> = match backend {
+            CredentialBackend::Keyring => Some(Arc::new(KeyringStore)),
+            CredentialBackend::EncryptedFile => None,
+        };
+
+        Ok(Self::new(
+            backend,
+            store,
+            keyring_available,
+            data_dir.join(CREDENTIAL_FILE_NAME),
+        ))
+    }
+
+    pub fn new(
+        backend: CredentialBackend,
+        store: Option<Arc<dyn CredentialStore>>,
+        keyring_available: bool,
+        file_path: PathBuf,
+    ) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(VaultState {
+                backend,
+                store,
+                keyring_available,
+            })),
+            file_path: Arc::new(file_path),
+        }
+    }
+
+    pub fn status(&self) -> CredentialStoreStatus {
+        let state = self.read_state();
+        CredentialStoreStatus {
+            backend: state.backend,
+            keyring_available: state.keyring_available,
+            locked: state.store.is_none(),
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.read_state().store.is_none()
+    }
+
+    pub fn file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    /// The backend in use; `CredentialsLocked` until the encrypted file is unlocked
+    pub fn current(&self) -> Result<Arc<dyn CredentialStore>> {
+        self.read_state()
+            .store
+            .clone()
+            .ok_or(Error::CredentialsLocked)
+    }
+
+    /// Unlock the selected encrypted file with its passphrase
+    /// Derives the key with Argon2, so call it off the async runtime.
+    pub fn unlock(&self, passphrase: &str) -> Result<()> {
+        if self.read_state().backend != CredentialBackend::EncryptedFile {
+            return Err(Error::InvalidInput(
+                "The encrypted credential file is not in use".to_string(),
+            ));
+        }
+        let store = self.open_file(passphrase)?;
+        self.write_state().store = Some(store);
+        Ok(())
+    }
+
+    /// The encrypted file as a store, reusing the instance in use if it is unlocked
+    /// Two instances of one file would each serialize only their own writes.
+    pub fn open_file(&self, passphrase: &str) -> Result<Arc<dyn CredentialStore>> {
+        {
+            let state = self.read_state();
+            if state.backend == CredentialBackend::EncryptedFile {
+                if let Some(store) = &state.store {
+                    return Ok(store.clone());
+                }
+            }
+        }
+        let store = EncryptedFileStore::open(self.file_path.to_path_buf(), passphrase)?;
+        Ok(Arc::new(store))
+    }
+
+    /// Use `store` as `backend` from now on
+    pub fn replace(&self, backend: CredentialBackend, store: Arc<dyn CredentialStore>) {
+        let mut state = self.write_state();
+        state.backend = backend;
+        state.store = Some(store);
+        if backend == CredentialBackend::Keyring {
+            state.keyring_available = true;
+        }
+    }
+
+    fn read_state(&self) -> RwLockReadGuard<'_, VaultState> {
+        // The state stays consistent even if a holder panicked
+        self.state.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_state(&self) -> RwLockWriteGuard<'_, VaultState> {
+        self.state.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CredentialStore for CredentialVault {
+    fn get(&self, entry: &str) -> Result<Option<Secret<String>>> {
+        self.current()?.get(entry)
+    }
+
+    fn set(&self, entry: &str, secret: &str) -> Result<()> {
+        self.current()?.set(entry, secret)
+    }
+
+    fn delete(&self, entry: &str) -> Result<()> {
+        self.current()?.delete(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("larch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(CREDENTIAL_FILE_NAME)
+    }
+
+    #[test]
+    fn test_encrypted_file_store_round_trip() {
+        let path = temp_path("credential-file");
+        let store = EncryptedFileStore::open(path.clone(), "correct horse").unwrap();
+        store
+            .set("taiga-api-token:ada@api.taiga.io", "s3cret-token")
+            .unwrap();
+        store
+            .set("taiga-refresh-token:ada@api.taiga.io", "refresh")
+            .unwrap();
+        store
+            .delete("taiga-refresh-token:ada@api.taiga.io")
+            .unwrap();
+        store.delete("missing").unwrap();
+
+        let data = fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&data).contains("s3cret-token"));
+
+        let reopened = EncryptedFileStore::open(path.clone(), "correct horse").unwrap();
+        let token = reopened.get("taiga-api-token:ada@api.taiga.io").unwrap();
+        assert_eq!(token.unwrap().expose_secret(), "s3cret-token");
+        assert!(reopened
+            .get("taiga-refresh-token:ada@api.taiga.io")
+            .unwrap()
+            .is_none());
+
+        assert!(matches!(
+            EncryptedFileStore::open(path.clone(), "wrong horse"),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_vault_stays_locked_until_unlocked() {
+        let path = temp_path("credential-vault");
+        let vault =
+            CredentialVault::new(CredentialBackend::EncryptedFile, None, false, path.clone());
+
+        assert!(vault.status().locked);
+        assert!(matches!(vault.get("entry"), Err(Error::CredentialsLocked)));
+
+        vault.unlock("passphrase").unwrap();
+        vault.set("entry", "value").unwrap();
+        assert!(!vault.is_locked());
+        assert_eq!(
+            vault.get("entry").unwrap().unwrap().expose_secret(),
+            "value"
+        );
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_vault_reuses_unlocked_file() {
+        let path = temp_path("credential-reuse");
+        let vault =
+            CredentialVault::new(CredentialBackend::EncryptedFile, None, false, path.clone());
+
+        vault.unlock("passphrase").unwrap();
+        let store = vault.current().unwrap();
+        assert!(Arc::ptr_eq(&vault.open_file("passphrase").unwrap(), &store));
+        vault.unlock("passphrase").unwrap();
+        assert!(Arc::ptr_eq(&vault.current().unwrap(), &store));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -1,51 +1,260 @@
-use crate::error::Result;
-use keyring::Entry;
-use secrecy::Secret;
+use crate::error::{Error, Result};
+use crate::services::accounts::LEGACY_ACCOUNT_ID;
+use crate::services::credential_store::CredentialStore;
+use secrecy::{ExposeSecret, Secret};
+use url::Url;
 
-const SERVICE_NAME: &str = "larch-app";
 const USER_NAME: &str = "taiga-api-token";
 const REFRESH_TOKEN_USER: &str = "taiga-refresh-token";
 
-pub fn set_api_token(token: &str) -> Result<()> {
-    let entry = Entry::new(SERVICE_NAME, USER_NAME)?;
-    entry.set_password(token)?;
-    Ok(())
+/// Whose secrets a credential entry holds: one user on one Taiga instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialKey {
+    host: String,
+    username: String,
+}
+
+impl CredentialKey {
+    /// Key for the API host (plus a non-default port) and the case-folded username
+    /// Scheme and path are ignored, so `https://Taiga.example.com/api/v1/` and
+    /// `https://taiga.example.com` share their entries.
+    pub fn new(api_url: &str, username: &str) -> Result<Self> {
+        let url = Url::parse(api_url)?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| Error::InvalidInput(format!("'{}' has no host", api_url)))?
+            .to_lowercase();
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host,
+        };
+
+        let username = username.trim().to_lowercase();
+        if username.is_empty() {
+            return Err(Error::InvalidInput("Username cannot be empty".to_string()));
+        }
+
+        Ok(Self { host, username })
+    }
+
+    fn entry(&self, user: &str) -> String {
+        format!("{}:{}@{}", user, self.username, self.host)
+    }
+}
+
+pub fn set_api_token(store: &dyn CredentialStore, key: &CredentialKey, token: &str) -> Result<()> {
+    store.set(&key.entry(USER_NAME), token)
+}
+
+/// The stored API token; `Unauthorized` if the user never signed in or signed out
+pub fn get_api_token(store: &dyn CredentialStore, key: &CredentialKey) -> Result<Secret<String>> {
+    store.get(&key.entry(USER_NAME))?.ok_or(Error::Unauthorized)
 }
 
-pub fn get_api_token() -> Result<Secret<String>> {
-    let entry = Entry::new(SERVICE_NAME, USER_NAME)?;
-    let secret = entry.get_password()?;
-    Ok(Secret::new(secret))
+pub fn delete_api_token(store: &dyn CredentialStore, key: &CredentialKey) -> Result<()> {
+    store.delete(&key.entry(USER_NAME))
 }
 
-pub fn delete_api_token() -> Result<()> {
-    let entry = Entry::new(SERVICE_NAME, USER_NAME)?;
-    if let Err(err) = entry.delete_password() {
-        if !matches!(err, keyring::Error::NoEntry) {
-            return Err(err.into());
+pub fn set_refresh_token(
+    store: &dyn CredentialStore,
+    key: &CredentialKey,
+    token: &str,
+) -> Result<()> {
+    store.set(&key.entry(REFRESH_TOKEN_USER), token)
+}
+
+pub fn get_refresh_token(
+    store: &dyn CredentialStore,
+    key: &CredentialKey,
+) -> Result<Secret<String>> {
+    store
+        .get(&key.entry(REFRESH_TOKEN_USER))?
+        .ok_or(Error::Unauthorized)
+}
+
+pub fn delete_refresh_token(store: &dyn CredentialStore, key: &CredentialKey) -> Result<()> {
+    store.delete(&key.entry(REFRESH_TOKEN_USER))
+}
+
+/// Copy the tokens of `key` to another store and remove them from the old one
+/// Used when the user switches credential backends. Returns whether anything was moved.
+pub fn move_tokens(
+    from: &dyn CredentialStore,
+    to: &dyn CredentialStore,
+    key: &CredentialKey,
+) -> Result<bool> {
+    let mut moved = false;
+    for user in [USER_NAME, REFRESH_TOKEN_USER] {
+        let entry = key.entry(user);
+        if let Some(secret) = from.get(&entry)? {
+            to.set(&entry, secret.expose_secret())?;
+            from.delete(&entry)?;
+            moved = true;
         }
     }
-    Ok(())
+    Ok(moved)
 }
 
-pub fn set_refresh_token(token: &str) -> Result<()> {
-    let entry = Entry::new(SERVICE_NAME, REFRESH_TOKEN_USER)?;
-    entry.set_password(token)?;
-    Ok(())
+/// Entry the legacy account's secret was stored under before entries were keyed by host and user
+/// Only the single-account setup wrote such entries; other accounts have none.
+fn legacy_entry(account_id: &str, user: &'static str) -> Option<&'static str> {
+    (account_id == LEGACY_ACCOUNT_ID).then_some(user)
+}
+
+/// API token of an account from before the migration, to look up its username
+pub fn get_legacy_api_token(
+    store: &dyn CredentialStore,
+    account_id: &str,
+) -> Result<Secret<String>> {
+    let entry = legacy_entry(account_id, USER_NAME).ok_or(Error::Unauthorized)?;
+    store.get(entry)?.ok_or(Error::Unauthorized)
+}
+
+/// Refresh token of an account from before the migration
+pub fn get_legacy_refresh_token(
+    store: &dyn CredentialStore,
+    account_id: &str,
+) -> Result<Secret<String>> {
+    let entry = legacy_entry(account_id, REFRESH_TOKEN_USER).ok_or(Error::Unauthorized)?;
+    store.get(entry)?.ok_or(Error::Unauthorized)
 }
 
-pub fn get_refresh_token() -> Result<Secret<String>> {
-    let entry = Entry::new(SERVICE_NAME, REFRESH_TOKEN_USER)?;
-    let secret = entry.get_password()?;
-    Ok(Secret::new(secret))
+/// Store a renewed token pair in an account's legacy entries
+pub fn set_legacy_tokens(
+    store: &dyn CredentialStore,
+    account_id: &str,
+    api_token: &str,
+    refresh_token: &str,
+) -> Result<()> {
+    let (Some(api_entry), Some(refresh_entry)) = (
+        legacy_entry(account_id, USER_NAME),
+        legacy_entry(account_id, REFRESH_TOKEN_USER),
+    ) else {
+        return Err(Error::Unauthorized);
+    };
+    store.set(refresh_entry, refresh_token)?;
+    store.set(api_entry, api_token)
 }
 
-pub fn delete_refresh_token() -> Result<()> {
-    let entry = Entry::new(SERVICE_NAME, REFRESH_TOKEN_USER)?;
-    if let Err(err) = entry.delete_password() {
-        if !matches!(err, keyring::Error::NoEntry) {
-            return Err(err.into());
+/// Move an account's secrets from its legacy entries to the entries of `key`
+/// Secrets already stored under `key` are newer and are kept. Returns whether
+/// anything was moved.
+pub fn migrate_legacy_entries(
+    store: &dyn CredentialStore,
+    account_id: &str,
+    key: &CredentialKey,
+) -> Result<bool> {
+    let mut migrated = false;
+    for user in [USER_NAME, REFRESH_TOKEN_USER] {
+        let Some(old) = legacy_entry(account_id, user) else {
+            continue;
+        };
+        let Some(secret) = store.get(old)? else {
+            continue;
+        };
+
+        let new = key.entry(user);
+        if store.get(&new)?.is_none() {
+            store.set(&new, secret.expose_secret())?;
+            migrated = true;
+        }
+        store.delete(old)?;
+    }
+    Ok(migrated)
+}
+
+/// Remove whatever an account still has in its legacy entries
+pub fn delete_legacy_entries(store: &dyn CredentialStore, account_id: &str) -> Result<()> {
+    for user in [USER_NAME, REFRESH_TOKEN_USER] {
+        if let Some(entry) = legacy_entry(account_id, user) {
+            store.delete(entry)?;
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_credential_key_normalizes_host_and_username() {
+        let key = CredentialKey::new("https://Taiga.Example.com/api/v1/", " Ada ").unwrap();
+        assert_eq!(
+            key,
+            CredentialKey::new("https://taiga.example.com", "ada").unwrap()
+        );
+        assert_eq!(
+            key,
+            CredentialKey::new("https://taiga.example.com:443/api/v1", "ADA").unwrap()
+        );
+
+        assert_ne!(
+            key,
+            CredentialKey::new("https://taiga.example.com:8443/api/v1", "ada").unwrap()
+        );
+        assert_ne!(
+            key,
+            CredentialKey::new("https://api.taiga.io/", "ada").unwrap()
+        );
+        assert!(CredentialKey::new("https://api.taiga.io/", "  ").is_err());
+    }
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<HashMap<String, String>>);
+
+    impl CredentialStore for MemoryStore {
+        fn get(&self, entry: &str) -> Result<Option<Secret<String>>> {
+            Ok(self.0.lock().unwrap().get(entry).cloned().map(Secret::new))
+        }
+
+        fn set(&self, entry: &str, secret: &str) -> Result<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(entry.to_string(), secret.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, entry: &str) -> Result<()> {
+            self.0.lock().unwrap().remove(entry);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_move_tokens_between_stores() {
+        let key = CredentialKey::new("https://api.taiga.io/", "ada").unwrap();
+        let keyring = MemoryStore::default();
+        let file = MemoryStore::default();
+        set_api_token(&keyring, &key, "token").unwrap();
+
+        assert!(move_tokens(&keyring, &file, &key).unwrap());
+        assert_eq!(get_api_token(&file, &key).unwrap().expose_secret(), "token");
+        assert!(matches!(
+            get_api_token(&keyring, &key),
+            Err(Error::Unauthorized)
+        ));
+        assert!(get_refresh_token(&file, &key).is_err());
+        assert!(!move_tokens(&keyring, &file, &key).unwrap());
+    }
+
+    #[test]
+    fn test_only_the_legacy_account_has_legacy_entries() {
+        let key = CredentialKey::new("https://api.taiga.io/", "ada").unwrap();
+        let store = MemoryStore::default();
+        store.set(USER_NAME, "token").unwrap();
+        store.set("taiga-api-token@work", "stray").unwrap();
+
+        assert!(!migrate_legacy_entries(&store, "work", &key).unwrap());
+        assert!(migrate_legacy_entries(&store, LEGACY_ACCOUNT_ID, &key).unwrap());
+        assert_eq!(
+            get_api_token(&store, &key).unwrap().expose_secret(),
+            "token"
+        );
+        assert!(store.get(USER_NAME).unwrap().is_none());
+        assert!(store.get("taiga-api-token@work").unwrap().is_some());
+    }
+}
@@ -104,15 +104,23 @@ async fn migrate_selected_projects(conn: &DatabaseConnection) -> Result<()> {
                 .map_err(|e| crate::error::Error::Database(e.to_string()))?;
 
             if my_projects_exists.is_none() {
-                let project_ids: Vec<i64> = serde_json::from_str(&config_entry.value)
-                    .map_err(|e| crate::error::Error::Database(format!("Invalid JSON in selected_projects: {}", e)))?;
+                let project_ids: Vec<i64> =
+                    serde_json::from_str(&config_entry.value).map_err(|e| {
+                        crate::error::Error::Database(format!(
+                            "Invalid JSON in selected_projects: {}",
+                            e
+                        ))
+                    })?;
 
                 let filter_data = serde_json::json!({
                     "project_ids": project_ids
                 });
 
                 saved_views::Entity::update_many()
-                    .col_expr(saved_views::Column::IsDefault, sea_orm::sea_query::Expr::value(false))
+                    .col_expr(
+                        saved_views::Column::IsDefault,
+                        sea_orm::sea_query::Expr::value(false),
+                    )
                     .exec(conn)
                     .await
                     .map_err(|e| crate::error::Error::Database(e.to_string()))?;
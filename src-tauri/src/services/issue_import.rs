@@ -0,0 +1,504 @@
+use crate::domain::project::ProjectMetadata;
+use crate::error::{Error, Result};
+use serde::Serialize;
+use taiga_client::models::CreateIssueRequest;
+
+/// Source format of an issue import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// Header row naming the columns, then one issue per record
+    Csv,
+    /// Open checklist or bullet items; plain lines when the text has no list items
+    Markdown,
+}
+
+impl ImportFormat {
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ImportFormat::Csv,
+            _ => ImportFormat::Markdown,
+        }
+    }
+}
+
+/// One issue read from the import source, with attributes still given by name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportRow {
+    /// 1-based line of the row in the source, for error reporting
+    pub line: usize,
+    pub subject: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub type_name: Option<String>,
+    pub priority: Option<String>,
+    pub severity: Option<String>,
+    pub assignee: Option<String>,
+    /// Why the record could not be read; the row is reported as failed
+    pub error: Option<String>,
+}
+
+impl ImportRow {
+    /// Rows with only a subject can go through Taiga's bulk endpoint
+    pub fn is_subject_only(&self) -> bool {
+        self.description.is_none()
+            && self.status.is_none()
+            && self.type_name.is_none()
+            && self.priority.is_none()
+            && self.severity.is_none()
+            && self.assignee.is_none()
+    }
+}
+
+/// Outcome of importing a single row
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRowResult {
+    pub line: usize,
+    pub subject: String,
+    pub issue_id: Option<i64>,
+    pub error: Option<String>,
+}
+
+impl ImportRowResult {
+    pub fn created(row: &ImportRow, issue_id: i64) -> Self {
+        Self {
+            line: row.line,
+            subject: row.subject.clone(),
+            issue_id: Some(issue_id),
+            error: None,
+        }
+    }
+
+    pub fn failed(row: &ImportRow, error: impl Into<String>) -> Self {
+        Self {
+            line: row.line,
+            subject: row.subject.clone(),
+            issue_id: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Parse the raw bytes of an import source
+/// CSV records are decoded one by one, so invalid UTF-8 only fails its own row.
+pub fn parse(data: &[u8], format: ImportFormat) -> Result<Vec<ImportRow>> {
+    match format {
+        ImportFormat::Csv => parse_csv(data),
+        ImportFormat::Markdown => Ok(parse_markdown(&String::from_utf8_lossy(data))),
+    }
+}
+
+/// Parse a CSV export; the header must have a subject (or title) column
+/// Recognised columns: subject, description, status, type, priority, severity, assignee.
+/// Other columns are ignored. A record that cannot be read becomes a row with
+/// `error` set, so the other rows can still be imported.
+pub fn parse_csv(data: &[u8]) -> Result<Vec<ImportRow>> {
+    let data = data.strip_prefix("\u{feff}".as_bytes()).unwrap_or(data);
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(data);
+    let mut records = reader.records();
+
+    let header = records
+        .next()
+        .ok_or_else(|| Error::InvalidInput("The CSV file is empty".to_string()))?
+        .map_err(csv_error)?;
+    let columns: Vec<Option<Column>> = header.iter().map(Column::from_header).collect();
+    if !columns.contains(&Some(Column::Subject)) {
+        return Err(Error::InvalidInput(
+            "The CSV header has no 'subject' column".to_string(),
+        ));
+    }
+
+    let mut rows = Vec::new();
+    for record in records {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                rows.push(ImportRow {
+                    line: e.position().map_or(0, |p| line_at(data, p.byte())),
+                    error: Some(format!("Invalid CSV: {}", e)),
+                    ..Default::default()
+                });
+                continue;
+            }
+        };
+        if record.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+
+        let mut row = ImportRow {
+            line: record.position().map_or(0, |p| line_at(data, p.byte())),
+            ..Default::default()
+        };
+        for (column, value) in columns.iter().zip(record.iter()) {
+            let Some(column) = column else { continue };
+            let value = value.trim();
+            let optional = || (!value.is_empty()).then(|| value.to_string());
+            match column {
+                Column::Subject => row.subject = single_line(value),
+                Column::Description => row.description = optional(),
+                Column::Status => row.status = optional(),
+                Column::Type => row.type_name = optional(),
+                Column::Priority => row.priority = optional(),
+                Column::Severity => row.severity = optional(),
+                Column::Assignee => row.assignee = optional(),
+            }
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Parse a Markdown checklist (`- [ ] Subject`) or bullet list into subject-only rows
+/// Headings, prose and checked items (`- [x] Done`) are skipped; text without any
+/// list item is read line by line.
+pub fn parse_markdown(text: &str) -> Vec<ImportRow> {
+    let lines: Vec<(usize, &str, bool)> = text
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim()))
+        .filter(|(_, l)| !l.is_empty() && !l.starts_with('#') && !l.starts_with("```"))
+        .map(|(line, l)| match strip_list_marker(l) {
+            Some(item) => (line, item, true),
+            None => (line, l, false),
+        })
+        .collect();
+
+    let has_list_items = lines.iter().any(|(_, _, is_item)| *is_item);
+
+    lines
+        .into_iter()
+        .filter(|(_, _, is_item)| *is_item || !has_list_items)
+        .filter_map(|(line, item, _)| Some((line, strip_checkbox(item)?)))
+        .filter(|(_, subject)| !subject.is_empty())
+        .map(|(line, subject)| ImportRow {
+            line,
+            subject: subject.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Turn a row into a create request, resolving names against the project metadata
+/// Names match case-insensitively; unknown names fail the row instead of being dropped.
+/// Assignees can be given by full name or username.
+pub fn resolve_row(
+    row: &ImportRow,
+    project_id: i64,
+    metadata: &ProjectMetadata,
+) -> std::result::Result<CreateIssueRequest, String> {
+    if let Some(error) = &row.error {
+        return Err(error.clone());
+    }
+    if row.subject.is_empty() {
+        return Err("Subject is empty".to_string());
+    }
+
+    let status = resolve_name(
+        "status",
+        row.status.as_deref(),
+        metadata.statuses.iter().map(|s| (s.id, s.name.as_str())),
+    )?;
+    let type_ = resolve_name(
+        "type",
+        row.type_name.as_deref(),
+        metadata.issue_types.iter().map(|t| (t.id, t.name.as_str())),
+    )?;
+    let priority = resolve_name(
+        "priority",
+        row.priority.as_deref(),
+        metadata.priorities.iter().map(|p| (p.id, p.name.as_str())),
+    )?;
+    let severity = resolve_name(
+        "severity",
+        row.severity.as_deref(),
+        metadata.severities.iter().map(|s| (s.id, s.name.as_str())),
+    )?;
+    let assigned_to = resolve_name(
+        "assignee",
+        row.assignee.as_deref(),
+        metadata
+            .members
+            .iter()
+            .filter_map(|m| m.user_id.map(|id| (id, m.full_name.as_str())))
+            .chain(
+                metadata
+                    .members
+                    .iter()
+                    .filter_map(|m| Some((m.user_id?, m.username.as_deref()?))),
+            ),
+    )?;
+
+    Ok(CreateIssueRequest {
+        project: project_id,
+        subject: row.subject.clone(),
+        description: row.description.clone(),
+        status,
+        type_,
+        priority,
+        severity,
+        assigned_to,
+        tags: vec![],
+        watchers: vec![],
+    })
+}
+
+fn resolve_name<'a>(
+    field: &str,
+    name: Option<&str>,
+    mut options: impl Iterator<Item = (i64, &'a str)>,
+) -> std::result::Result<Option<i64>, String> {
+    let Some(name) = name else {
+        return Ok(None);
+    };
+    options
+        .find(|(_, option)| option.trim().eq_ignore_ascii_case(name.trim()))
+        .map(|(id, _)| Some(id))
+        .ok_or_else(|| format!("Unknown {} '{}'", field, name))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Subject,
+    Description,
+    Status,
+    Type,
+    Priority,
+    Severity,
+    Assignee,
+}
+
+impl Column {
+    fn from_header(header: &str) -> Option<Self> {
+        match header.trim().to_lowercase().replace('_', " ").as_str() {
+            "subject" | "title" | "summary" => Some(Column::Subject),
+            "description" => Some(Column::Description),
+            "status" => Some(Column::Status),
+            "type" | "issue type" => Some(Column::Type),
+            "priority" => Some(Column::Priority),
+            "severity" => Some(Column::Severity),
+            "assignee" | "assigned to" => Some(Column::Assignee),
+            _ => None,
+        }
+    }
+}
+
+/// 1-based line a record starts on
+/// The reader reports its position before the previous line break and any blank
+/// lines it skipped, so those are stepped over first.
+fn line_at(bytes: &[u8], byte: u64) -> usize {
+    let mut start = (byte as usize).min(bytes.len());
+    while start < bytes.len() && matches!(bytes[start], b'\r' | b'\n') {
+        start += 1;
+    }
+    bytes[..start].iter().filter(|&&b| b == b'\n').count() + 1
+}
+
+fn csv_error(e: csv::Error) -> Error {
+    Error::InvalidInput(format!("Invalid CSV: {}", e))
+}
+
+fn strip_list_marker(line: &str) -> Option<&str> {
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        return Some(rest.trim());
+    }
+
+    // Ordered items: "1. Subject" or "1) Subject"
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .map(str::trim)
+}
+
+/// The subject of an open item, `None` for a checked one since that work is done
+fn strip_checkbox(item: &str) -> Option<&str> {
+    if item.starts_with("[x]") || item.starts_with("[X]") {
+        return None;
+    }
+    Some(item.strip_prefix("[ ]").unwrap_or(item).trim())
+}
+
+/// Subjects are sent one per line to Taiga, so they must not span lines
+fn single_line(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::project::{IssueStatus, Member, Priority};
+
+    fn metadata() -> ProjectMetadata {
+        ProjectMetadata {
+            id: 1,
+            statuses: vec![IssueStatus {
+                id: 10,
+                name: "In progress".to_string(),
+                color: "#fff".to_string(),
+                is_closed: false,
+            }],
+            user_story_statuses: vec![],
+            task_statuses: vec![],
+            members: vec![Member {
+                id: 5,
+                user_id: Some(7),
+                full_name: "Ada Lovelace".to_string(),
+                username: Some("ada".to_string()),
+                role_name: "Dev".to_string(),
+                photo: None,
+            }],
+            priorities: vec![Priority {
+                id: 20,
+                name: "High".to_string(),
+                color: "#f00".to_string(),
+                order: 1,
+            }],
+            severities: vec![],
+            issue_types: vec![],
+            tags_colors: vec![],
+            issue_custom_attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_maps_columns_and_quotes() {
+        let text = "Title,Status,Assigned To,Ignored\n\
+                    \"Crash, on start\",In progress,Ada Lovelace,x\n\
+                    \n\
+                    \"Multi\nline \"\"quoted\"\"\",,,\n";
+
+        let rows = parse_csv(text.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].subject, "Crash, on start");
+        assert_eq!(rows[0].status.as_deref(), Some("In progress"));
+        assert_eq!(rows[0].assignee.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(rows[1].line, 4);
+        assert_eq!(rows[1].subject, "Multi line \"quoted\"");
+        assert!(rows[1].is_subject_only());
+    }
+
+    #[test]
+    fn test_parse_csv_requires_subject_column() {
+        assert!(matches!(
+            parse_csv(b"status,priority\nNew,High\n"),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(parse_csv(b""), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_parse_csv_crlf_and_escaped_quotes() {
+        let text = "subject,description\r\n\
+                    \"Say \"\"hi\"\", then leave\",\"First\r\nSecond\"\r\n\
+                    Plain,\r\n";
+
+        let rows = parse_csv(text.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].subject, "Say \"hi\", then leave");
+        assert_eq!(rows[0].description.as_deref(), Some("First\r\nSecond"));
+        assert_eq!(rows[1].line, 4);
+        assert_eq!(rows[1].subject, "Plain");
+        assert_eq!(rows[1].description, None);
+    }
+
+    #[test]
+    fn test_parse_csv_reports_unreadable_record_as_row() {
+        let data = b"subject\nFirst\nBad \xff byte\nLast\n";
+
+        let rows = parse_csv(data).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].subject, "First");
+        assert_eq!(rows[1].line, 3);
+        assert!(rows[1].error.is_some());
+        assert!(resolve_row(&rows[1], 1, &metadata()).is_err());
+        assert_eq!(rows[2].subject, "Last");
+    }
+
+    #[test]
+    fn test_parse_markdown_checklist_skips_prose_and_checked_items() {
+        let text = "# Release checklist\n\
+                    Things to do:\n\
+                    - [ ] Update docs\n\
+                    - [x] Tag release\n\
+                    2. Announce\n";
+
+        let rows = parse_markdown(text);
+
+        let subjects: Vec<_> = rows.iter().map(|r| r.subject.as_str()).collect();
+        assert_eq!(subjects, vec!["Update docs", "Announce"]);
+        assert_eq!(rows[0].line, 3);
+    }
+
+    #[test]
+    fn test_parse_markdown_plain_lines() {
+        let rows = parse_markdown("First issue\n\nSecond issue\n");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].subject, "Second issue");
+        assert_eq!(rows[1].line, 3);
+    }
+
+    #[test]
+    fn test_resolve_row_by_name() {
+        let row = ImportRow {
+            line: 2,
+            subject: "Crash".to_string(),
+            status: Some("in progress".to_string()),
+            priority: Some("HIGH".to_string()),
+            assignee: Some("Ada Lovelace".to_string()),
+            ..Default::default()
+        };
+
+        let request = resolve_row(&row, 1, &metadata()).unwrap();
+
+        assert_eq!(request.status, Some(10));
+        assert_eq!(request.priority, Some(20));
+        assert_eq!(request.assigned_to, Some(7));
+        assert_eq!(request.severity, None);
+    }
+
+    #[test]
+    fn test_resolve_row_matches_assignee_username() {
+        let row = ImportRow {
+            line: 2,
+            subject: "Crash".to_string(),
+            assignee: Some("ADA".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_row(&row, 1, &metadata()).unwrap().assigned_to,
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn test_resolve_row_reports_unknown_names() {
+        let row = ImportRow {
+            line: 3,
+            subject: "Crash".to_string(),
+            severity: Some("Blocker".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_row(&row, 1, &metadata()).unwrap_err(),
+            "Unknown severity 'Blocker'"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::error::{Error, Result};
+use url::Url;
+
+/// An issue as people refer to it: project slug plus the `#ref` shown in Taiga
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueReference {
+    pub project_slug: String,
+    pub ref_: i64,
+}
+
+/// Web UI base URL for a Taiga API URL
+/// Cloud: https://api.taiga.io -> https://tree.taiga.io
+/// Self-hosted: https://taiga.example.com/api/v1 -> https://taiga.example.com
+pub fn web_base_url(api_url: &str) -> String {
+    if let Ok(parsed) = Url::parse(api_url) {
+        if parsed.host_str() == Some("api.taiga.io") {
+            "https://tree.taiga.io".to_string()
+        } else {
+            // Strip common API path suffixes
+            let mut result = api_url.to_string();
+            for suffix in &["/api/v1", "/api/v2", "/api"] {
+                if result.ends_with(suffix) {
+                    result = result.trim_end_matches(suffix).to_string();
+                    break;
+                }
+            }
+            result.trim_end_matches('/').to_string()
+        }
+    } else {
+        api_url
+            .trim_end_matches("/api/v1")
+            .trim_end_matches('/')
+            .to_string()
+    }
+}
+
+/// Parse `slug#ref` or a pasted issue link such as `https://tree.taiga.io/project/acme/issue/412`
+/// Links must live under `web_base`, the instance Larch is signed in to.
+pub fn parse_issue_reference(input: &str, web_base: &str) -> Result<IssueReference> {
+    let input = input.trim();
+
+    if let Some((slug, number)) = input.split_once('#') {
+        if !input.contains("://") {
+            return Ok(IssueReference {
+                project_slug: parse_slug(slug)?,
+                ref_: parse_ref(number)?,
+            });
+        }
+    }
+
+    let link = Url::parse(input).map_err(|_| {
+        Error::InvalidInput(format!(
+            "'{}' is neither a Taiga issue link nor project#ref",
+            input
+        ))
+    })?;
+    let base = Url::parse(web_base)?;
+    if link.scheme() != base.scheme()
+        || link.host_str() != base.host_str()
+        || link.port_or_known_default() != base.port_or_known_default()
+    {
+        return Err(Error::InvalidInput(format!(
+            "The link does not point to {}",
+            web_base
+        )));
+    }
+
+    let rest = link
+        .path()
+        .strip_prefix(base.path().trim_end_matches('/'))
+        .unwrap_or_default();
+    let segments: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        ["project", slug, "issue", number] => Ok(IssueReference {
+            project_slug: parse_slug(slug)?,
+            ref_: parse_ref(number)?,
+        }),
+        _ => Err(Error::InvalidInput(format!(
+            "'{}' is not a link to a Taiga issue",
+            input
+        ))),
+    }
+}
+
+fn parse_slug(slug: &str) -> Result<String> {
+    let slug = slug.trim();
+    if slug.is_empty()
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(Error::InvalidInput(format!(
+            "'{}' is not a project slug",
+            slug
+        )));
+    }
+    Ok(slug.to_string())
+}
+
+fn parse_ref(number: &str) -> Result<i64> {
+    match number.trim().parse::<i64>() {
+        Ok(r) if r > 0 => Ok(r),
+        _ => Err(Error::InvalidInput(format!(
+            "'{}' is not an issue number",
+            number.trim()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(slug: &str, ref_: i64) -> IssueReference {
+        IssueReference {
+            project_slug: slug.to_string(),
+            ref_,
+        }
+    }
+
+    #[test]
+    fn test_web_base_url_for_cloud_and_self_hosted() {
+        assert_eq!(
+            web_base_url("https://api.taiga.io"),
+            "https://tree.taiga.io"
+        );
+        assert_eq!(
+            web_base_url("https://taiga.example.com/api/v1"),
+            "https://taiga.example.com"
+        );
+        assert_eq!(
+            web_base_url("https://example.com/taiga/api/v1"),
+            "https://example.com/taiga"
+        );
+    }
+
+    #[test]
+    fn test_parse_slug_and_ref() {
+        let web = "https://tree.taiga.io";
+        assert_eq!(
+            parse_issue_reference(" acme#412 ", web).unwrap(),
+            reference("acme", 412)
+        );
+        assert!(parse_issue_reference("acme#", web).is_err());
+        assert!(parse_issue_reference("#412", web).is_err());
+        assert!(parse_issue_reference("acme#-3", web).is_err());
+    }
+
+    #[test]
+    fn test_parse_issue_link_reverses_web_base() {
+        assert_eq!(
+            parse_issue_reference(
+                "https://tree.taiga.io/project/acme/issue/412",
+                "https://tree.taiga.io"
+            )
+            .unwrap(),
+            reference("acme", 412)
+        );
+        assert_eq!(
+            parse_issue_reference(
+                "https://example.com/taiga/project/ops-team/issue/7?kanban-status=2#comment-1",
+                "https://example.com/taiga"
+            )
+            .unwrap(),
+            reference("ops-team", 7)
+        );
+    }
+
+    #[test]
+    fn test_parse_issue_link_rejects_other_hosts_and_kinds() {
+        let web = "https://tree.taiga.io";
+        assert!(parse_issue_reference("https://evil.example/project/acme/issue/412", web).is_err());
+        assert!(parse_issue_reference("https://tree.taiga.io/project/acme/us/412", web).is_err());
+        assert!(parse_issue_reference("not a link", web).is_err());
+    }
+}
@@ -1,4 +1,11 @@
+pub mod accounts;
+pub mod auth_client;
+pub mod connection_settings;
+pub mod credential_store;
 pub mod credentials;
 pub mod db;
-pub mod token_refresh;
+pub mod issue_import;
+pub mod issue_links;
+pub mod project_metadata;
+pub mod transfers;
 pub mod view_sanitizer;
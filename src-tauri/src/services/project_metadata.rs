@@ -0,0 +1,215 @@
+use crate::domain::custom_attribute::CustomAttribute;
+use crate::domain::project::{
+    IssueStatus, IssueType, Member, Priority, ProjectMetadata, Severity, TagColor,
+};
+use crate::error::Result;
+use secrecy::Secret;
+use taiga_client::TaigaClient;
+
+/// Statuses, members and other options of a project, as the issue forms use them
+/// Only the project itself is required; the other lists are left empty when
+/// their request fails.
+pub async fn fetch_project_metadata(
+    client: &TaigaClient,
+    token: Secret<String>,
+    pid: i64,
+) -> Result<ProjectMetadata> {
+    let project_res = client.get_project(&token, pid).await;
+
+    let priorities_res = match client.get_priorities(&token, pid).await {
+        Ok(p) => Ok(p),
+        Err(e) => {
+            log::error!("get_priorities failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let severities_res = match client.get_severities(&token, pid).await {
+        Ok(s) => Ok(s),
+        Err(e) => {
+            log::error!("get_severities failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let types_res = match client.get_issue_types(&token, pid).await {
+        Ok(t) => Ok(t),
+        Err(e) => {
+            log::error!("get_issue_types failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let tags_res = match client.get_project_tags_colors(&token, pid).await {
+        Ok(t) => Ok(t),
+        Err(e) => {
+            log::error!("get_project_tags_colors failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let attributes_res = match client.get_issue_custom_attributes(&token, pid).await {
+        Ok(a) => Ok(a),
+        Err(e) => {
+            log::error!("get_issue_custom_attributes failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let members_res = match client.get_memberships(&token, pid).await {
+        Ok(m) => Ok(m),
+        Err(e) => {
+            log::error!("get_memberships failed for {}: {}", pid, e);
+            Err(e)
+        }
+    };
+
+    let dto = match project_res {
+        Ok(p) => p,
+        Err(e) => {
+            log::error!("Failed to fetch project {} metadata: {}", pid, e);
+            return Err(e.into());
+        }
+    };
+
+    let statuses: Vec<IssueStatus> = dto
+        .issue_statuses
+        .unwrap_or_default()
+        .into_iter()
+        .map(|s| IssueStatus {
+            id: s.id,
+            name: s.name,
+            color: s.color,
+            is_closed: s.is_closed,
+        })
+        .collect();
+
+    let user_story_statuses: Vec<IssueStatus> = dto
+        .us_statuses
+        .unwrap_or_default()
+        .into_iter()
+        .map(|s| IssueStatus {
+            id: s.id,
+            name: s.name,
+            color: s.color,
+            is_closed: s.is_closed,
+        })
+        .collect();
+
+    let task_statuses: Vec<IssueStatus> = dto
+        .task_statuses
+        .unwrap_or_default()
+        .into_iter()
+        .map(|s| IssueStatus {
+            id: s.id,
+            name: s.name,
+            color: s.color,
+            is_closed: s.is_closed,
+        })
+        .collect();
+
+    // Memberships carry no username; the project's member list has it
+    let usernames: std::collections::HashMap<i64, String> = dto
+        .members
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|m| Some((m.id, m.username?)))
+        .collect();
+
+    let members: Vec<Member> = members_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch memberships for {}: {}", pid, e);
+            vec![]
+        })
+        .into_iter()
+        .map(|m| Member {
+            id: m.id,
+            user_id: m.user,
+            full_name: m.full_name.unwrap_or_default(),
+            username: m.user.and_then(|id| usernames.get(&id).cloned()),
+            role_name: m.role_name,
+            photo: m.photo,
+        })
+        .collect();
+
+    let priorities: Vec<Priority> = priorities_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch priorities for {}: {}", pid, e);
+            vec![]
+        })
+        .into_iter()
+        .map(|p| Priority {
+            id: p.id,
+            name: p.name,
+            color: p.color,
+            order: p.order,
+        })
+        .collect();
+
+    let severities: Vec<Severity> = severities_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch severities for {}: {}", pid, e);
+            vec![]
+        })
+        .into_iter()
+        .map(|s| Severity {
+            id: s.id,
+            name: s.name,
+            color: s.color,
+            order: s.order,
+        })
+        .collect();
+
+    let issue_types: Vec<IssueType> = types_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch issue types for {}: {}", pid, e);
+            vec![]
+        })
+        .into_iter()
+        .map(|t| IssueType {
+            id: t.id,
+            name: t.name,
+            color: t.color,
+            order: t.order,
+        })
+        .collect();
+
+    let tags_colors: Vec<TagColor> = tags_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch tags colors for {}: {}", pid, e);
+            serde_json::Value::Null
+        })
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .map(|(name, color)| TagColor {
+                    name: name.clone(),
+                    color: color.as_str().map(|s| s.to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut issue_custom_attributes: Vec<CustomAttribute> = attributes_res
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch custom attributes for {}: {}", pid, e);
+            vec![]
+        })
+        .iter()
+        .map(|a| a.into())
+        .collect();
+    issue_custom_attributes.sort_by_key(|a| (a.order, a.id));
+
+    Ok(ProjectMetadata {
+        id: pid,
+        statuses,
+        user_story_statuses,
+        task_statuses,
+        members,
+        priorities,
+        severities,
+        issue_types,
+        tags_colors,
+        issue_custom_attributes,
+    })
+}
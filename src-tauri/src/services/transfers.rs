@@ -0,0 +1,159 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use serde::Serialize;
+use taiga_client::transfer::TransferProgress;
+
+/// Event emitted to the frontend while a download or upload runs
+pub const TRANSFER_PROGRESS_EVENT: &str = "transfer-progress";
+
+/// Minimum time between two progress events for the same transfer
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Config key holding the attachment upload limit in bytes
+pub const MAX_UPLOAD_SIZE_KEY: &str = "max_upload_size_bytes";
+
+/// Upload limit used until the user sets one (100 MiB)
+pub const DEFAULT_MAX_UPLOAD_SIZE: u64 = 100 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferProgressEvent {
+    pub transfer_id: String,
+    pub transferred: u64,
+    pub total: Option<u64>,
+}
+
+/// Cancellation flags of the running transfers, keyed by the frontend's transfer ID
+#[derive(Debug, Default)]
+pub struct TransferRegistry {
+    active: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl TransferRegistry {
+    /// Track a new transfer and return its cancellation flag
+    /// An ID that is still running is rejected so its flag stays cancellable.
+    pub fn register(&self, transfer_id: &str) -> Result<Arc<AtomicBool>> {
+        match self.lock().entry(transfer_id.to_string()) {
+            Entry::Occupied(_) => Err(Error::InvalidInput(format!(
+                "Transfer '{}' is already running",
+                transfer_id
+            ))),
+            Entry::Vacant(entry) => Ok(Arc::clone(entry.insert(Arc::new(AtomicBool::new(false))))),
+        }
+    }
+
+    /// Ask a running transfer to stop; false when no such transfer is running
+    pub fn cancel(&self, transfer_id: &str) -> bool {
+        match self.lock().get(transfer_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn finish(&self, transfer_id: &str) {
+        self.lock().remove(transfer_id);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<AtomicBool>>> {
+        // A panic while holding the lock cannot leave the map inconsistent
+        self.active.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Rate-limits progress updates so large transfers don't flood the event bus
+/// The first and the final update always pass.
+#[derive(Debug, Default)]
+pub struct ProgressThrottle {
+    last_emit: Option<Instant>,
+}
+
+impl ProgressThrottle {
+    pub fn should_emit(&mut self, progress: &TransferProgress, now: Instant) -> bool {
+        let finished = progress.total == Some(progress.transferred);
+        let due = match self.last_emit {
+            Some(last) => now.duration_since(last) >= PROGRESS_INTERVAL,
+            None => true,
+        };
+        if finished || due {
+            self.last_emit = Some(now);
+            return true;
+        }
+        false
+    }
+}
+
+/// Read the stored upload limit, falling back to the default when unset or invalid
+pub fn parse_max_upload_size(value: Option<&str>) -> u64 {
+    match value.map(|v| v.trim().parse::<u64>()) {
+        Some(Ok(limit)) if limit > 0 => limit,
+        Some(_) => {
+            log::warn!("Ignoring invalid {} value {:?}", MAX_UPLOAD_SIZE_KEY, value);
+            DEFAULT_MAX_UPLOAD_SIZE
+        }
+        None => DEFAULT_MAX_UPLOAD_SIZE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_only_known_transfers() {
+        let registry = TransferRegistry::default();
+        let flag = registry.register("t1").unwrap();
+
+        assert!(!registry.cancel("other"));
+        assert!(registry.cancel("t1"));
+        assert!(flag.load(Ordering::Relaxed));
+
+        registry.finish("t1");
+        assert!(!registry.cancel("t1"));
+    }
+
+    #[test]
+    fn test_register_rejects_running_transfer_id() {
+        let registry = TransferRegistry::default();
+        let flag = registry.register("t1").unwrap();
+
+        assert!(matches!(
+            registry.register("t1"),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(registry.cancel("t1"));
+        assert!(flag.load(Ordering::Relaxed));
+
+        registry.finish("t1");
+        assert!(registry.register("t1").is_ok());
+    }
+
+    #[test]
+    fn test_throttle_passes_first_due_and_final_updates() {
+        let mut throttle = ProgressThrottle::default();
+        let start = Instant::now();
+        let progress = |transferred| TransferProgress {
+            transferred,
+            total: Some(100),
+        };
+
+        assert!(throttle.should_emit(&progress(0), start));
+        assert!(!throttle.should_emit(&progress(10), start + Duration::from_millis(50)));
+        assert!(throttle.should_emit(&progress(20), start + Duration::from_millis(120)));
+        assert!(throttle.should_emit(&progress(100), start + Duration::from_millis(130)));
+    }
+
+    #[test]
+    fn test_max_upload_size_falls_back_to_default() {
+        assert_eq!(parse_max_upload_size(Some("1048576")), 1048576);
+        assert_eq!(parse_max_upload_size(None), DEFAULT_MAX_UPLOAD_SIZE);
+        assert_eq!(parse_max_upload_size(Some("0")), DEFAULT_MAX_UPLOAD_SIZE);
+        assert_eq!(parse_max_upload_size(Some("lots")), DEFAULT_MAX_UPLOAD_SIZE);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::domain::facets::FilterId;
 use crate::error::Result;
 use crate::repositories::Repository;
 use serde::{Deserialize, Serialize};
@@ -6,29 +7,35 @@ use std::collections::HashSet;
 #[derive(Debug, Deserialize, Serialize)]
 struct FilterData {
     #[serde(skip_serializing_if = "Option::is_none")]
-    status_ids: Option<Vec<i64>>,
+    status_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     status_exclude: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    assignee_ids: Option<Vec<i64>>,
+    assignee_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     assignee_exclude: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    project_ids: Option<Vec<i64>>,
+    project_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     project_exclude: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    priority_ids: Option<Vec<i64>>,
+    priority_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     priority_exclude: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    severity_ids: Option<Vec<i64>>,
+    severity_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     severity_exclude: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    type_ids: Option<Vec<i64>>,
+    type_ids: Option<Vec<FilterId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     type_exclude: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_user_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone_id: Option<FilterId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_milestone: Option<bool>,
 }
 
 pub async fn sanitize_all_views<R: Repository>(
@@ -62,7 +69,7 @@ pub async fn sanitize_all_views<R: Repository>(
         if let Some(ref valid_projects) = valid_projects {
             if let Some(ref mut project_ids) = new_filter.project_ids {
                 let original_len = project_ids.len();
-                project_ids.retain(|id| valid_projects.contains(id));
+                project_ids.retain(|id| keep_id(id, valid_projects));
                 if project_ids.len() != original_len {
                     changed = true;
                 }
@@ -72,7 +79,7 @@ pub async fn sanitize_all_views<R: Repository>(
         if let Some(ref valid_statuses) = valid_statuses {
             if let Some(ref mut status_ids) = new_filter.status_ids {
                 let original_len = status_ids.len();
-                status_ids.retain(|id| valid_statuses.contains(id));
+                status_ids.retain(|id| keep_id(id, valid_statuses));
                 if status_ids.len() != original_len {
                     changed = true;
                 }
@@ -81,8 +88,9 @@ pub async fn sanitize_all_views<R: Repository>(
 
         if changed {
             let new_filter_json = serde_json::to_string(&new_filter)?;
-            
-            repo.update_view(view.id, &view.name, &new_filter_json).await?;
+
+            repo.update_view(view.id, &view.name, &new_filter_json)
+                .await?;
             log::info!("Sanitized view {}: removed orphan IDs", view.name);
         }
     }
@@ -90,6 +98,15 @@ pub async fn sanitize_all_views<R: Repository>(
     Ok(())
 }
 
+/// The valid IDs come from the active account, so only plain IDs can be checked
+/// Scoped IDs of other accounts are left alone.
+fn keep_id(id: &FilterId, valid: &HashSet<i64>) -> bool {
+    match id {
+        FilterId::Plain(id) => valid.contains(id),
+        FilterId::Scoped(_) => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,6 +114,10 @@ mod tests {
     use crate::repositories::SqliteRepository;
     use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Schema};
 
+    fn plain(ids: &[i64]) -> Option<Vec<FilterId>> {
+        Some(ids.iter().map(|id| FilterId::Plain(*id)).collect())
+    }
+
     async fn create_test_db() -> DatabaseConnection {
         let db_url = "sqlite::memory:";
         let conn = Database::connect(db_url).await.unwrap();
@@ -112,7 +133,9 @@ mod tests {
         let stmt = builder.build(&stmt);
         conn.execute(stmt).await.unwrap();
 
-        let stmt = schema.create_table_from_entity(saved_views::Entity).to_owned();
+        let stmt = schema
+            .create_table_from_entity(saved_views::Entity)
+            .to_owned();
         let stmt = builder.build(&stmt);
         conn.execute(stmt).await.unwrap();
 
@@ -143,11 +166,12 @@ mod tests {
             .unwrap();
 
         let sanitized_view = repo.get_view(view.id).await.unwrap().unwrap();
-        let sanitized_filter: FilterData = serde_json::from_str(&sanitized_view.filter_data).unwrap();
+        let sanitized_filter: FilterData =
+            serde_json::from_str(&sanitized_view.filter_data).unwrap();
 
-        assert_eq!(sanitized_filter.project_ids, Some(vec![1, 2]));
-        assert_eq!(sanitized_filter.status_ids, Some(vec![10]));
-        assert_eq!(sanitized_filter.assignee_ids, Some(vec![5, 6]));
+        assert_eq!(sanitized_filter.project_ids, plain(&[1, 2]));
+        assert_eq!(sanitized_filter.status_ids, plain(&[10]));
+        assert_eq!(sanitized_filter.assignee_ids, plain(&[5, 6]));
     }
 
     #[tokio::test]
@@ -164,7 +188,9 @@ mod tests {
             .await
             .unwrap();
 
-        sanitize_all_views(&repo, Some(&[]), Some(&[])).await.unwrap();
+        sanitize_all_views(&repo, Some(&[]), Some(&[]))
+            .await
+            .unwrap();
 
         let unchanged_view = repo.get_view(system_view.id).await.unwrap().unwrap();
         assert_eq!(unchanged_view.filter_data, filter_with_orphans);
@@ -190,10 +216,11 @@ mod tests {
             .unwrap();
 
         let unchanged_view = repo.get_view(view.id).await.unwrap().unwrap();
-        let unchanged_filter: FilterData = serde_json::from_str(&unchanged_view.filter_data).unwrap();
+        let unchanged_filter: FilterData =
+            serde_json::from_str(&unchanged_view.filter_data).unwrap();
 
-        assert_eq!(unchanged_filter.project_ids, Some(vec![1, 2]));
-        assert_eq!(unchanged_filter.status_ids, Some(vec![10, 20]));
+        assert_eq!(unchanged_filter.project_ids, plain(&[1, 2]));
+        assert_eq!(unchanged_filter.status_ids, plain(&[10, 20]));
     }
 
     #[tokio::test]
@@ -211,12 +238,15 @@ mod tests {
             .await
             .unwrap();
 
-        sanitize_all_views(&repo, Some(&[]), Some(&[])).await.unwrap();
+        sanitize_all_views(&repo, Some(&[]), Some(&[]))
+            .await
+            .unwrap();
 
         let sanitized_view = repo.get_view(view.id).await.unwrap().unwrap();
-        let sanitized_filter: FilterData = serde_json::from_str(&sanitized_view.filter_data).unwrap();
+        let sanitized_filter: FilterData =
+            serde_json::from_str(&sanitized_view.filter_data).unwrap();
 
-        assert_eq!(sanitized_filter.project_ids, Some(vec![]));
-        assert_eq!(sanitized_filter.status_ids, Some(vec![]));
+        assert_eq!(sanitized_filter.project_ids, plain(&[]));
+        assert_eq!(sanitized_filter.status_ids, plain(&[]));
     }
 }
@@ -50,3 +50,12 @@ export const CMD_DELETE_VIEW = 'delete_view';
 export const CMD_SWITCH_VIEW = 'switch_view';
 export const CMD_SET_DEFAULT_VIEW = 'set_default_view';
 export const CMD_SANITIZE_VIEWS = 'sanitize_views';
+
+// Account Commands
+export const CMD_LIST_ACCOUNTS = 'list_accounts';
+export const CMD_SWITCH_ACCOUNT = 'switch_account';
+
+// Credential Storage Commands
+export const CMD_GET_CREDENTIAL_STORE_STATUS = 'get_credential_store_status';
+export const CMD_UNLOCK_CREDENTIAL_STORE = 'unlock_credential_store';
+export const CMD_SET_CREDENTIAL_BACKEND = 'set_credential_backend';
@@ -13,6 +13,8 @@ export interface Issue {
 	priority?: number;
 	severity?: number;
 	issue_type?: number;
+	/** Account the row came from on the aggregated dashboard */
+	account_id?: string;
 }
 
 export interface User {
@@ -47,6 +49,7 @@ export interface Member {
 	id: number;
 	user_id?: number;
 	full_name: string;
+	username?: string;
 	role_name: string;
 	photo?: string;
 }
@@ -227,3 +230,22 @@ export interface HistoryEntry {
 	is_edited: boolean;
 	changes: FieldChange[];
 }
+
+export interface AccountSummary {
+	id: string;
+	name: string;
+	api_url: string;
+	username?: string;
+	active: boolean;
+	signed_in: boolean;
+}
+
+export type CredentialBackend = 'keyring' | 'encrypted_file';
+
+export interface CredentialStoreStatus {
+	backend: CredentialBackend;
+	/** Whether the OS keyring answered at startup */
+	keyring_available: boolean;
+	/** The encrypted file is selected but its passphrase was not entered yet */
+	locked: boolean;
+}